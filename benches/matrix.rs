@@ -0,0 +1,92 @@
+//! Compares the lookups of `MatrixWrapper` with the nested `Vec<Vec<T>>` representation it
+//! replaced: `cargo bench --bench matrix`.
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent_of_code_2023::{matrix::MatrixWrapper, point::Point};
+
+const ITERATIONS: u32 = 20;
+
+/// The former representation, kept to compare with.
+struct Nested(Vec<Vec<u32>>);
+
+impl Nested {
+    fn enumerate(&self) -> impl Iterator<Item = (Point, &u32)> {
+        self.0.iter().enumerate().flat_map(move |(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(j, value)| (Point::new(i as i32, j as i32), value))
+        })
+    }
+    fn at(&self, point: Point) -> Option<&u32> {
+        if point.row < 0 || point.col < 0 {
+            return None;
+        }
+        let (row, col) = (point.row as usize, point.col as usize);
+        if row < self.0.len() && self.0.first().is_some_and(|r| col < r.len()) {
+            Some(&self.0[row][col])
+        } else {
+            None
+        }
+    }
+}
+
+/// Median duration of `ITERATIONS` runs of `f`.
+fn time<R>(mut f: impl FnMut() -> R) -> Duration {
+    let mut samples = (0..ITERATIONS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .collect::<Vec<Duration>>();
+    samples.sort();
+    samples[samples.len() / 2]
+}
+
+fn compare(name: &str, nested: Duration, flat: Duration) {
+    println!(
+        "{name:<24} nested {nested:>12?}  flat {flat:>12?}  x{:.2}",
+        nested.as_secs_f64() / flat.as_secs_f64()
+    );
+}
+
+fn main() {
+    for size in [140, 1000] {
+        let rows = (0..size)
+            .map(|row| (0..size).map(|col| row * size + col).collect())
+            .collect::<Vec<Vec<u32>>>();
+        let nested = Nested(rows.clone());
+        let flat = MatrixWrapper::from_rows(rows).unwrap();
+        let points = (0..size as i32)
+            .flat_map(|row| (0..size as i32).map(move |col| Point::new(row, col)))
+            .collect::<Vec<Point>>();
+        println!("{size}x{size} cells");
+        compare(
+            "enumerate",
+            time(|| nested.enumerate().map(|(_, v)| *v as u64).sum::<u64>()),
+            time(|| flat.enumerate().map(|(_, v)| *v as u64).sum::<u64>()),
+        );
+        compare(
+            "at",
+            time(|| points.iter().filter_map(|&p| nested.at(p)).count()),
+            time(|| points.iter().filter_map(|&p| flat.at(p)).count()),
+        );
+        compare(
+            "enumerate then at below",
+            time(|| {
+                nested
+                    .enumerate()
+                    .filter_map(|(p, _)| nested.at(Point::new(p.row + 1, p.col)))
+                    .count()
+            }),
+            time(|| {
+                flat.enumerate()
+                    .filter_map(|(p, _)| flat.at(Point::new(p.row + 1, p.col)))
+                    .count()
+            }),
+        );
+    }
+}
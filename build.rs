@@ -0,0 +1,49 @@
+//! Generates one test per puzzle example found in
+//! `examples/<year>/day_<day>/example_<number>.txt`.
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+fn numbered(name: &str, prefix: &str, suffix: &str) -> Option<u32> {
+    name.strip_prefix(prefix)?
+        .strip_suffix(suffix)?
+        .parse()
+        .ok()
+}
+
+/// The entries of `dir` named `<prefix><number><suffix>`, with their number.
+fn numbered_entries(dir: &Path, prefix: &str, suffix: &str) -> Vec<(u32, PathBuf)> {
+    fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|entry| {
+            let number = numbered(entry.file_name().to_str()?, prefix, suffix)?;
+            Some((number, entry.path()))
+        })
+        .collect()
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=examples");
+    let mut cases = vec![];
+    for (year, year_dir) in numbered_entries(Path::new("examples"), "", "") {
+        for (day, day_dir) in numbered_entries(&year_dir, "day_", "") {
+            for (example, _) in numbered_entries(&day_dir, "example_", ".txt") {
+                cases.push((year, day, example));
+            }
+        }
+    }
+    cases.sort_unstable();
+    let tests = cases
+        .iter()
+        .map(|(year, day, example)| {
+            format!(
+                "#[test]\nfn year_{year}_day_{day}_example_{example}() {{\n    check_example({year}, {day}, {example});\n}}\n"
+            )
+        })
+        .collect::<String>();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("example_tests.rs"), tests).unwrap();
+}
@@ -0,0 +1,139 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Serialize, Serializer};
+
+/// Answer of a puzzle part. Integer answers are compared by value whatever their variant.
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Big(i128),
+    Text(String),
+}
+
+impl Answer {
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Unsigned(value) => Some(i128::from(*value)),
+            Answer::Signed(value) => Some(i128::from(*value)),
+            Answer::Big(value) => Some(*value),
+            Answer::Text(_) => None,
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.as_integer(), other.as_integer()) {
+            (Some(value), Some(other_value)) => value == other_value,
+            (None, None) => self.to_string() == other.to_string(),
+            _ => false,
+        }
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Big(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Integers are serialized as numbers, text as a string.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Unsigned(value) => serializer.serialize_u64(*value),
+            Answer::Signed(value) => serializer.serialize_i64(*value),
+            Answer::Big(value) => serializer.serialize_i128(*value),
+            Answer::Text(value) => serializer.serialize_str(value),
+        }
+    }
+}
+
+/// Reads integers as the smallest variant able to hold them, anything else as text.
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(if let Ok(value) = s.parse() {
+            Answer::Unsigned(value)
+        } else if let Ok(value) = s.parse() {
+            Answer::Signed(value)
+        } else if let Ok(value) = s.parse() {
+            Answer::Big(value)
+        } else {
+            Answer::Text(s.to_string())
+        })
+    }
+}
+
+impl From<u16> for Answer {
+    fn from(value: u16) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Unsigned(value.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Unsigned(value)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Signed(value)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        Answer::Big(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integer_answers_should_be_compared_by_value() {
+        assert_eq!(Answer::Unsigned(42), Answer::Signed(42));
+        assert_eq!(Answer::Big(42), Answer::Unsigned(42));
+        assert_ne!(Answer::Signed(-42), Answer::Unsigned(42));
+        assert_ne!(Answer::Text("42".into()), Answer::Unsigned(42));
+    }
+
+    #[test]
+    fn answer_should_be_parsed_as_smallest_variant() {
+        assert!(matches!("42".parse(), Ok(Answer::Unsigned(42))));
+        assert!(matches!("-42".parse(), Ok(Answer::Signed(-42))));
+        assert!(matches!(
+            "18446744073709551616".parse(),
+            Ok(Answer::Big(18446744073709551616))
+        ));
+        assert!(matches!("abc".parse(), Ok(Answer::Text(t)) if t == "abc"));
+    }
+}
@@ -0,0 +1,211 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    answer::Answer,
+    runner::{Outcome, Run},
+};
+
+/// Accepted answers of the puzzle inputs, keyed by day then part. Integers too large for TOML
+/// can be written as strings:
+///
+/// ```toml
+/// [1]
+/// 1 = 55002
+/// 2 = 55093
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), Answer>);
+
+impl Answers {
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        let days: BTreeMap<String, BTreeMap<String, toml::Value>> =
+            toml::from_str(content).map_err(|e| e.to_string())?;
+        let mut answers = HashMap::new();
+        for (day, parts) in days {
+            let day = day
+                .parse::<u8>()
+                .map_err(|_| format!("invalid day `{day}`"))?;
+            for (part, answer) in parts {
+                let part = part
+                    .parse::<u8>()
+                    .map_err(|_| format!("invalid part `{part}` of day {day}"))?;
+                let answer = match answer {
+                    toml::Value::String(answer) => {
+                        let Ok(answer) = answer.parse();
+                        answer
+                    }
+                    toml::Value::Integer(answer) => Answer::Signed(answer),
+                    other => return Err(format!("invalid answer `{other}` for {day}.{part}")),
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+        Ok(Self(answers))
+    }
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch {
+        expected: Answer,
+        actual: String,
+    },
+    NoExpectedAnswer,
+    /// The input of a run with an expected answer is missing, which fails the verification.
+    MissingInput {
+        expected: Answer,
+    },
+}
+
+/// The answers file of `year`, `{year}` in `path` standing for it.
+pub fn year_path(path: &Path, year: u16) -> PathBuf {
+    PathBuf::from(path.to_string_lossy().replace("{year}", &year.to_string()))
+}
+
+pub fn verify(run: &Run, answers: &Answers) -> Verdict {
+    match (answers.get(run.day, run.part), &run.outcome) {
+        (None, _) => Verdict::NoExpectedAnswer,
+        (Some(expected), Outcome::MissingInput) => Verdict::MissingInput {
+            expected: expected.clone(),
+        },
+        (Some(expected), Outcome::Solved(actual)) if expected == actual => Verdict::Match,
+        (Some(expected), actual) => Verdict::Mismatch {
+            expected: expected.clone(),
+            actual: actual.to_string(),
+        },
+    }
+}
+
+/// Prints the verdict of every run and returns whether all the expected answers were found.
+pub fn print_verdicts(runs: &[Run], answers: &Answers) -> bool {
+    let mut mismatches = vec![];
+    let mut missing_inputs = vec![];
+    for run in runs {
+        let verdict = verify(run, answers);
+        let status = match &verdict {
+            Verdict::Match => "ok",
+            Verdict::Mismatch { .. } => "FAILED",
+            Verdict::NoExpectedAnswer => "no expected answer",
+            Verdict::MissingInput { .. } => "MISSING INPUT",
+        };
+        println!("day {:>2} part {}: {status}", run.day, run.part);
+        match verdict {
+            Verdict::Mismatch { expected, actual } => mismatches.push((run, expected, actual)),
+            Verdict::MissingInput { expected } => missing_inputs.push((run, expected)),
+            _ => {}
+        }
+    }
+    for (run, expected) in &missing_inputs {
+        println!(
+            "\nday {} part {}: {} is missing, expected {expected}",
+            run.day, run.part, run.input
+        );
+    }
+    for (run, expected, actual) in &mismatches {
+        println!("\nday {} part {}:", run.day, run.part);
+        println!("- {expected}");
+        println!("+ {actual}");
+    }
+    mismatches.is_empty() && missing_inputs.is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{input::InputSource, solution::DEFAULT_YEAR};
+
+    fn run(day: u8, part: u8, outcome: Outcome) -> Run {
+        Run {
+            year: DEFAULT_YEAR,
+            day,
+            part,
+            input: InputSource::Stdin,
+            outcome,
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn answers_should_be_read_from_toml() {
+        let answers =
+            Answers::from_toml("[1]\n1 = 55002\n2 = \"abc\"\n[2]\n1 = \"18446744073709551616\"\n")
+                .unwrap();
+        assert_eq!(answers.get(1, 1), Some(&Answer::Unsigned(55002)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::Text("abc".into())));
+        assert_eq!(answers.get(2, 1), Some(&Answer::Big(18446744073709551616)));
+        assert_eq!(answers.get(2, 2), None);
+    }
+
+    #[test]
+    fn answers_path_should_be_the_year_one() {
+        assert_eq!(
+            year_path(Path::new("answers/{year}.toml"), 2022),
+            Path::new("answers/2022.toml")
+        );
+        assert_eq!(
+            year_path(Path::new("answers.toml"), 2022),
+            Path::new("answers.toml")
+        );
+    }
+
+    #[test]
+    fn answers_should_reject_invalid_day() {
+        assert!(Answers::from_toml("[first]\n1 = 1\n").is_err());
+    }
+
+    #[test]
+    fn missing_input_with_expected_answer_should_fail() {
+        let answers = Answers::from_toml("[1]\n1 = 42\n").unwrap();
+        assert!(!print_verdicts(
+            &[run(1, 1, Outcome::MissingInput)],
+            &answers
+        ));
+        assert!(print_verdicts(
+            &[run(2, 1, Outcome::MissingInput)],
+            &answers
+        ));
+        assert!(print_verdicts(
+            &[run(1, 1, Outcome::Solved(Answer::Unsigned(42)))],
+            &answers
+        ));
+    }
+
+    #[test]
+    fn verify_should_compare_answers() {
+        let answers = Answers::from_toml("[1]\n1 = 42\n2 = 43\n").unwrap();
+        assert_eq!(
+            verify(&run(1, 1, Outcome::Solved(Answer::Unsigned(42))), &answers),
+            Verdict::Match
+        );
+        assert_eq!(
+            verify(&run(1, 2, Outcome::Solved(Answer::Unsigned(42))), &answers),
+            Verdict::Mismatch {
+                expected: Answer::Signed(43),
+                actual: "42".into()
+            }
+        );
+        assert_eq!(
+            verify(&run(2, 1, Outcome::Solved(Answer::Unsigned(42))), &answers),
+            Verdict::NoExpectedAnswer
+        );
+        assert_eq!(
+            verify(&run(1, 1, Outcome::MissingInput), &answers),
+            Verdict::MissingInput {
+                expected: Answer::Unsigned(42)
+            }
+        );
+        assert_eq!(
+            verify(&run(2, 1, Outcome::MissingInput), &answers),
+            Verdict::NoExpectedAnswer
+        );
+    }
+}
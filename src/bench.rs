@@ -0,0 +1,204 @@
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    parse_error::ParseError,
+    solution::{Params, Solution},
+    trace,
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    fn from_samples(samples: &mut [Duration]) -> Self {
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        Self {
+            min: samples[0],
+            median,
+            mean: samples.iter().sum::<Duration>() / len as u32,
+            max: samples[len - 1],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Report {
+    pub day: u8,
+    pub part: u8,
+    pub iterations: u32,
+    pub answer: Answer,
+    /// Whether every run found the same answer.
+    pub stable: bool,
+    /// Time to parse the input, `None` when the parsed input can't solve the part.
+    pub parse: Option<Stats>,
+    /// Time to solve the parsed input, `None` when it can't solve the part.
+    pub solve: Option<Stats>,
+    pub total: Stats,
+}
+
+/// Runs `part` of `solution` `iterations` times, timing the parsing of the input then the solving
+/// of the parsed input, or the whole run when the parsed input can't solve the part.
+/// Returns `None` when the part isn't solved or `iterations` is zero.
+pub fn bench(
+    solution: &dyn Solution,
+    part: u8,
+    input_content: &str,
+    params: &Params,
+    iterations: u32,
+) -> Result<Option<Report>, ParseError> {
+    let locate = |e: ParseError| e.locate(solution.day(), input_content);
+    let mut parse_samples = vec![];
+    let mut solve_samples = vec![];
+    let mut total_samples = vec![];
+    let mut answers: Vec<Answer> = vec![];
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = black_box(solution.parse(input_content).map_err(locate)?);
+        let parse_time = start.elapsed();
+        let start = Instant::now();
+        if let Some(res) = trace::in_day(solution.day(), || parsed.solve(part, params)) {
+            answers.push(black_box(res.map_err(locate)?));
+            let solve_time = start.elapsed();
+            parse_samples.push(parse_time);
+            solve_samples.push(solve_time);
+            total_samples.push(parse_time + solve_time);
+            continue;
+        }
+        let start = Instant::now();
+        match solution.part_with(part, input_content, params) {
+            Some(res) => answers.push(black_box(res?)),
+            None => return Ok(None),
+        };
+        total_samples.push(start.elapsed());
+    }
+    let split = |samples: &mut Vec<Duration>| {
+        (samples.len() == total_samples.len()).then(|| Stats::from_samples(samples))
+    };
+    let Some(answer) = answers.first().cloned() else {
+        return Ok(None);
+    };
+    Ok(Some(Report {
+        day: solution.day(),
+        part,
+        iterations,
+        stable: answers.iter().all(|a| *a == answer),
+        answer,
+        parse: split(&mut parse_samples),
+        solve: split(&mut solve_samples),
+        total: Stats::from_samples(&mut total_samples),
+    }))
+}
+
+pub fn print_report(report: &Report) {
+    println!(
+        "Benchmark of step {} of challenge {} over {} runs",
+        report.part, report.day, report.iterations
+    );
+    println!("Answer: {}", report.answer);
+    if !report.stable {
+        println!("Warning: the answer changed between runs");
+    }
+    println!(
+        "{:<6} | {:>12} | {:>12} | {:>12} | {:>12}",
+        "", "min", "median", "mean", "max"
+    );
+    let rows = [
+        ("parse", report.parse.as_ref()),
+        ("solve", report.solve.as_ref()),
+        ("total", Some(&report.total)),
+    ];
+    for (name, stats) in rows {
+        let Some(stats) = stats else {
+            continue;
+        };
+        println!(
+            "{:<6} | {:>12} | {:>12} | {:>12} | {:>12}",
+            name,
+            format!("{:?}", stats.min),
+            format!("{:?}", stats.median),
+            format!("{:?}", stats.mean),
+            format!("{:?}", stats.max)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{registry, solution::DEFAULT_YEAR};
+
+    #[test]
+    fn stats_should_use_middle_samples_for_median() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(
+            stats,
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_micros(2500),
+                mean: Duration::from_micros(2500),
+                max: Duration::from_millis(4),
+            }
+        );
+    }
+
+    #[test]
+    fn bench_should_run_every_iteration() {
+        let day_6 = registry::find(DEFAULT_YEAR, 6).unwrap();
+        let report = bench(day_6, 1, "Time: 7\nDistance: 9", &Params::new(), 3)
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.iterations, 3);
+        assert_eq!(report.answer, Answer::Unsigned(4));
+        assert!(report.stable);
+        assert!(report.total.min <= report.total.max);
+        let (parse, solve) = (report.parse.unwrap(), report.solve.unwrap());
+        assert!(parse.min + solve.min <= report.total.min);
+    }
+
+    struct Lines;
+
+    impl Solution for Lines {
+        fn day(&self) -> u8 {
+            1
+        }
+        fn title(&self) -> &'static str {
+            "Lines"
+        }
+        fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+            Ok((input_content.lines().count() as u64).into())
+        }
+    }
+
+    #[test]
+    fn bench_should_only_time_whole_runs_when_parsed_input_cannot_solve() {
+        let report = bench(&Lines, 1, "a\nb", &Params::new(), 3)
+            .unwrap()
+            .unwrap();
+        assert_eq!(report.answer, Answer::Unsigned(2));
+        assert!(report.parse.is_none() && report.solve.is_none());
+    }
+
+    #[test]
+    fn bench_should_skip_missing_part() {
+        let day_15 = registry::find(DEFAULT_YEAR, 15).unwrap();
+        assert!(bench(day_15, 2, "rn=1", &Params::new(), 3)
+            .unwrap()
+            .is_none());
+    }
+}
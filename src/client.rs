@@ -0,0 +1,228 @@
+use std::{
+    env,
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::answer::Answer;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_ENV_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_ENV_VAR: &str = "AOC_BASE_URL";
+const USER_AGENT: &str = "advent_of_code_2023 solutions runner";
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    Status(u16, String),
+    Transport(String),
+    Io(io::Error),
+}
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "No session token, set {SESSION_ENV_VAR} or write it in the session file"
+            ),
+            ClientError::Status(status, body) => {
+                write!(f, "Request failed with status {status}: {}", body.trim())
+            }
+            ClientError::Transport(error) => write!(f, "Request failed: {error}"),
+            ClientError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<io::Error> for ClientError {
+    fn from(value: io::Error) -> Self {
+        ClientError::Io(value)
+    }
+}
+
+impl From<ureq::Error> for ClientError {
+    fn from(value: ureq::Error) -> Self {
+        match value {
+            ureq::Error::Status(status, response) => {
+                ClientError::Status(status, response.into_string().unwrap_or_default())
+            }
+            ureq::Error::Transport(transport) => ClientError::Transport(transport.to_string()),
+        }
+    }
+}
+
+/// Reads the session token from the environment, falling back on `session_file`.
+pub fn session_token(session_file: &Path) -> Result<String, ClientError> {
+    env::var(SESSION_ENV_VAR)
+        .ok()
+        .or_else(|| fs::read_to_string(session_file).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+        .ok_or(ClientError::MissingSession)
+}
+
+/// The root URL from the environment, falling back on the `configured` one then on the website.
+pub fn base_url(configured: Option<&str>) -> String {
+    env::var(BASE_URL_ENV_VAR)
+        .ok()
+        .or_else(|| configured.map(str::to_string))
+        .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+}
+
+/// Client of the Advent of Code website, or of any server exposing the same routes.
+pub struct Client {
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+    fn url(&self, year: u16, day: u8, route: &str) -> String {
+        format!("{}/{year}/day/{day}{route}", self.base_url)
+    }
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let response = ureq::get(&self.url(year, day, "/input"))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .call()?;
+        Ok(response.into_string()?)
+    }
+    /// Posts `answer` for the given part and returns the page of the response.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<String, ClientError> {
+        let response = ureq::post(&self.url(year, day, "/answer"))
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[
+                ("level", &part.to_string()),
+                ("answer", &answer.to_string()),
+            ])?;
+        Ok(response.into_string()?)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    AlreadyCached(PathBuf),
+}
+
+/// Downloads the input of `day` of `year` into `path`, keeping an existing file unless `force`
+/// is set.
+pub fn fetch_to(
+    client: &Client,
+    year: u16,
+    day: u8,
+    path: &Path,
+    force: bool,
+) -> Result<Fetched, ClientError> {
+    if path.exists() && !force {
+        return Ok(Fetched::AlreadyCached(path.to_path_buf()));
+    }
+    let input_content = client.fetch_input(year, day)?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, input_content)?;
+    Ok(Fetched::Downloaded(path.to_path_buf()))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// Serves a single request with `status` and `body`, returning the request head it received.
+    pub(crate) fn serve_once(status: u16, body: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut head = String::new();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                    content_length = length.trim().parse().unwrap();
+                }
+                if line == "\r\n" {
+                    break;
+                }
+                head.push_str(&line);
+            }
+            let mut request_body = vec![0; content_length];
+            io::Read::read_exact(&mut reader, &mut request_body).unwrap();
+            head.push_str(&String::from_utf8(request_body).unwrap());
+            write!(
+                stream,
+                "HTTP/1.1 {status} Response\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            )
+            .unwrap();
+            head
+        });
+        (base_url, handle)
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc_client_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn fetch_should_download_input_with_session_cookie() {
+        let (base_url, server) = serve_once(200, "1abc2\n");
+        let path = temp_path("download.txt");
+        let _ = fs::remove_file(&path);
+        let client = Client::new(&base_url, "secret");
+        let fetched = fetch_to(&client, 2022, 1, &path, false).unwrap();
+        let request = server.join().unwrap();
+        assert_eq!(fetched, Fetched::Downloaded(path.clone()));
+        assert!(request.starts_with("GET /2022/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=secret"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1abc2\n");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn fetch_should_keep_existing_input_without_force() {
+        let path = temp_path("cached.txt");
+        fs::write(&path, "cached").unwrap();
+        let client = Client::new("http://127.0.0.1:9", "secret");
+        let fetched = fetch_to(&client, 2023, 1, &path, false).unwrap();
+        assert_eq!(fetched, Fetched::AlreadyCached(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "cached");
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn fetch_should_report_http_errors() {
+        let (base_url, server) = serve_once(404, "Not found");
+        let path = temp_path("missing.txt");
+        let client = Client::new(&base_url, "secret");
+        let error = fetch_to(&client, 2023, 26, &path, true).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(error, ClientError::Status(404, _)));
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,410 @@
+//! The commands of the command line, printing their results and reporting failures through the
+//! exit code.
+use std::{
+    io::{self, Stdout},
+    num::NonZeroUsize,
+    path::Path,
+    process::ExitCode,
+    time::Instant,
+};
+
+use serde::Serialize;
+
+use crate::{
+    answers::{self, Answers},
+    bench,
+    client::{self, Client, Fetched},
+    config::DaysParams,
+    input::{self, InputSource},
+    output::{BenchRecord, Format, RecordWriter, RunRecord},
+    registry::{self, PartKey},
+    repl::{self, Repl},
+    runner::{self, InputLayout, Outcome, Run},
+    scaffold,
+    solution::{Params, Solution},
+    submit::{self, Attempt, History, Verdict},
+    watch::Watcher,
+};
+
+/// Runs every part of every registered solution of `year` on its input, on `jobs` worker
+/// threads, failing unless every part is solved.
+pub fn run_all(
+    year: u16,
+    inputs: &InputLayout,
+    params: &DaysParams,
+    format: Format,
+    jobs: NonZeroUsize,
+) -> ExitCode {
+    let start = Instant::now();
+    let runs = runner::run_all(year, inputs, params, jobs);
+    let elapsed = start.elapsed();
+    match RecordWriter::stdout(format) {
+        Some(mut writer) => {
+            for run in &runs {
+                if !write_record(&mut writer, &RunRecord::from(run)) {
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        None => {
+            runner::print_table(&runs);
+            println!("Ran {} parts in {elapsed:?}, jobs: {jobs}", runs.len());
+        }
+    }
+    if runs.iter().all(|r| matches!(r.outcome, Outcome::Solved(_))) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Runs, or benchmarks when `bench` gives a number of iterations, a step on every source.
+pub fn run_steps(
+    solution: &dyn Solution,
+    step: u8,
+    sources: &[InputSource],
+    params: &Params,
+    bench: Option<u32>,
+    dedent: bool,
+    format: Format,
+) -> ExitCode {
+    let mut writer = RecordWriter::stdout(format);
+    let mut exit_code = ExitCode::SUCCESS;
+    for source in sources {
+        let input_content = match source.read() {
+            Ok(input_content) if dedent => input::dedent(&input_content),
+            Ok(input_content) => input_content,
+            Err(error) => {
+                eprintln!("{error}");
+                exit_code = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        let res = match bench {
+            Some(iterations) => bench_step(
+                solution,
+                step,
+                source,
+                &input_content,
+                params,
+                iterations,
+                &mut writer,
+            ),
+            None => run_step(solution, step, source, &input_content, params, &mut writer),
+        };
+        if !res {
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    exit_code
+}
+
+/// Re-runs a step whenever its file sources or the day examples change, only returning when a
+/// source can't be watched.
+pub fn watch(
+    solution: &dyn Solution,
+    step: u8,
+    sources: Vec<InputSource>,
+    params: &Params,
+    examples_dir: &Path,
+) -> ExitCode {
+    let mut inputs = vec![];
+    for source in sources {
+        match source {
+            InputSource::File(path) => inputs.push(path),
+            InputSource::Stdin => {
+                eprintln!("The standard input can't be watched");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    Watcher::new(solution, step, params.clone(), inputs, examples_dir).run()
+}
+
+/// Returns whether the record could be written.
+fn write_record<T: Serialize>(writer: &mut RecordWriter<Stdout>, record: &T) -> bool {
+    match writer.write(record) {
+        Ok(()) => true,
+        Err(error) => {
+            eprintln!("Can't write output: {error}");
+            false
+        }
+    }
+}
+
+/// Returns whether the input could be parsed.
+fn run_step(
+    solution: &dyn Solution,
+    step: u8,
+    source: &InputSource,
+    input_content: &str,
+    params: &Params,
+    writer: &mut Option<RecordWriter<Stdout>>,
+) -> bool {
+    let (challenge_id, title) = (solution.day(), solution.title());
+    let run = runner::run_part(solution, step, source, input_content, params);
+    if let Some(writer) = writer {
+        if let Outcome::Failed(error) = &run.outcome {
+            eprintln!("{source}: {error}");
+        }
+        return write_record(writer, &RunRecord::from(&run))
+            && !matches!(run.outcome, Outcome::Failed(_));
+    }
+    match run {
+        Run {
+            outcome: Outcome::Solved(res),
+            elapsed,
+            ..
+        } => println!(
+            "Result for step {step} of challenge {challenge_id} ({title}) on {source} is {res} in {elapsed:?}"
+        ),
+        Run {
+            outcome: Outcome::Failed(error),
+            ..
+        } => {
+            eprintln!("{source}: {error}");
+            return false;
+        }
+        _ => println!("Challenge {challenge_id} or step {step} doesn't exists"),
+    }
+    true
+}
+
+/// Returns whether the input could be parsed.
+fn bench_step(
+    solution: &dyn Solution,
+    step: u8,
+    source: &InputSource,
+    input_content: &str,
+    params: &Params,
+    iterations: u32,
+    writer: &mut Option<RecordWriter<Stdout>>,
+) -> bool {
+    let challenge_id = solution.day();
+    let outcome = match bench::bench(solution, step, input_content, params, iterations) {
+        Ok(Some(report)) => match writer {
+            Some(writer) => return write_record(writer, &BenchRecord::new(source, &report)),
+            None => {
+                println!("Input {source}");
+                bench::print_report(&report);
+                return true;
+            }
+        },
+        Ok(None) => Outcome::MissingPart,
+        Err(error) => {
+            eprintln!("{source}: {error}");
+            Outcome::Failed(error)
+        }
+    };
+    let failed = matches!(outcome, Outcome::Failed(_));
+    match writer {
+        Some(writer) => {
+            let record = BenchRecord::unsolved(challenge_id, step, source, &outcome);
+            write_record(writer, &record) && !failed
+        }
+        None if failed => false,
+        None => {
+            println!("Challenge {challenge_id} or step {step} doesn't exists");
+            true
+        }
+    }
+}
+
+pub fn verify(
+    year: u16,
+    challenge_id: Option<u8>,
+    inputs: &InputLayout,
+    params: &DaysParams,
+    answers_path: &Path,
+    jobs: NonZeroUsize,
+) -> ExitCode {
+    let answers = match std::fs::read_to_string(answers_path).map(|c| Answers::from_toml(&c)) {
+        Ok(Ok(answers)) => answers,
+        Ok(Err(e)) => {
+            eprintln!("Invalid answers file {}: {e}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("Can't read answers file {}: {e}", answers_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    let runs = match challenge_id {
+        Some(challenge_id) => {
+            let Some(solution) = registry::find(year, challenge_id) else {
+                println!("Challenge {challenge_id} of {year} doesn't exists");
+                return ExitCode::FAILURE;
+            };
+            runner::run_solutions(&[solution], inputs, params, jobs)
+        }
+        None => runner::run_all(year, inputs, params, jobs),
+    };
+    if answers::print_verdicts(&runs, &answers) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Downloads the input of `day` of `year` unless it's already there.
+pub fn fetch(
+    year: u16,
+    day: u8,
+    force: bool,
+    base_url: &str,
+    session_file: &Path,
+    inputs: &InputLayout,
+) -> ExitCode {
+    let path = inputs.path(year, day);
+    if path.exists() && !force {
+        println!(
+            "{} already exists, use --force to download it again",
+            path.display()
+        );
+        return ExitCode::SUCCESS;
+    }
+    let session = match client::session_token(session_file) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let client = Client::new(base_url, &session);
+    match client::fetch_to(&client, year, day, &path, force) {
+        Ok(Fetched::Downloaded(path)) => println!("Downloaded {}", path.display()),
+        Ok(Fetched::AlreadyCached(path)) => println!("{} already exists", path.display()),
+        Err(error) => {
+            eprintln!("Can't fetch input of day {day} of {year}: {error}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Scaffolds the module, the input and the first example of `day` of `year` in the project at
+/// `root`.
+pub fn new_day(
+    root: &Path,
+    inputs: &InputLayout,
+    examples_dir: &Path,
+    year: u16,
+    day: u8,
+) -> ExitCode {
+    match scaffold::scaffold(root, &inputs.path(year, day), examples_dir, year, day) {
+        Ok(paths) => {
+            for path in paths {
+                println!("Created {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(error) => {
+            eprintln!("Can't create challenge {day} of {year}: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Reads repl commands on the standard input for the solution of `day` of `year`.
+pub fn repl(year: u16, day: u8, source: InputSource, params: &Params) -> ExitCode {
+    let Some(solution) = registry::find(year, day) else {
+        println!("Challenge {day} of {year} doesn't exists");
+        return ExitCode::FAILURE;
+    };
+    let repl = match Repl::new(solution, source, params.clone()) {
+        Ok(repl) => repl,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{}", repl::HELP);
+    match repl.run(io::stdin().lock(), io::stdout()) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Submits `answer`, or the answer computed from the day input, unless the history rules it out.
+pub fn submit(
+    key: PartKey,
+    answer: Option<String>,
+    inputs: &InputLayout,
+    params: &Params,
+    base_url: &str,
+    session_file: &Path,
+    history_path: &Path,
+) -> ExitCode {
+    let PartKey { year, day, part } = key;
+    let answer = match answer {
+        Some(answer) => {
+            let Ok(answer) = answer.parse();
+            answer
+        }
+        None => {
+            let Some(solution) = registry::find(year, day) else {
+                println!("Challenge {day} of {year} doesn't exists");
+                return ExitCode::FAILURE;
+            };
+            let source = InputSource::File(inputs.path(year, day));
+            let input_content = match source.read() {
+                Ok(input_content) => input_content,
+                Err(error) => {
+                    eprintln!("{error}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            match runner::run_part(solution, part, &source, &input_content, params).outcome {
+                Outcome::Solved(answer) => answer,
+                Outcome::Failed(error) => {
+                    eprintln!("{source}: {error}");
+                    return ExitCode::FAILURE;
+                }
+                _ => {
+                    println!("Challenge {day} or step {part} doesn't exists");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    };
+    let mut history = match History::load(history_path) {
+        Ok(history) => history,
+        Err(error) => {
+            eprintln!("Can't read history {}: {error}", history_path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(refusal) = history.check(year, day, part, &answer) {
+        println!("Not submitting {answer} for {key}: {refusal}");
+        return ExitCode::FAILURE;
+    }
+    let session = match client::session_token(session_file) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let page = match Client::new(base_url, &session).submit_answer(year, day, part, &answer) {
+        Ok(page) => page,
+        Err(error) => {
+            eprintln!("Can't submit answer of {key}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let verdict = Verdict::from_response(&page);
+    history.record(Attempt::new(year, day, part, &answer, verdict));
+    if let Err(error) = history.save(history_path) {
+        eprintln!("Can't write history {}: {error}", history_path.display());
+    }
+    println!("Answer {answer} for {key} is {verdict}");
+    println!("{}", submit::response_message(&page));
+    if verdict == Verdict::Correct {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -0,0 +1,262 @@
+//! Optional settings of the command line, read from `aoc.toml` in the project root or from
+//! `advent_of_code_2023/config.toml` in the XDG config directory. Relative paths are relative to
+//! the directory the command runs in, and command line flags override every setting. The days
+//! settings are keyed by year then day, whatever year the commands work on:
+//!
+//! ```toml
+//! year = 2023
+//! inputs_dir = "inputs"
+//! input_pattern = "{year}/day_{day}.txt"
+//! examples_dir = "examples"
+//! answers_path = "answers/{year}.toml"
+//! format = "text"
+//! base_url = "https://adventofcode.com"
+//! session_file = ".session"
+//! history_path = "submissions.json"
+//!
+//! [days.2023.2]
+//! input = "inputs/other_games.txt"
+//! params = { bag = { red = 12, green = 13, blue = 14 } }
+//! ```
+use std::{
+    collections::BTreeMap,
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::{de, Deserialize, Deserializer};
+
+use crate::{
+    output::Format,
+    registry,
+    runner::InputLayout,
+    solution::{Params, DEFAULT_YEAR},
+};
+
+pub const PROJECT_FILE: &str = "aoc.toml";
+
+/// Parameters of the days setting some, keyed by year and day.
+pub type DaysParams = BTreeMap<(u16, u8), Params>;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Year of the calendar the commands work on.
+    pub year: u16,
+    pub inputs_dir: PathBuf,
+    /// File name of the inputs, `{year}` and `{day}` standing for the year and the day.
+    pub input_pattern: String,
+    pub examples_dir: PathBuf,
+    /// Answers file, `{year}` standing for the year.
+    pub answers_path: PathBuf,
+    pub format: Format,
+    /// Root URL of the website, `$AOC_BASE_URL` taking precedence.
+    pub base_url: Option<String>,
+    pub session_file: PathBuf,
+    pub history_path: PathBuf,
+    /// Settings of the days, keyed by year and day.
+    #[serde(deserialize_with = "year_day_keys")]
+    pub days: BTreeMap<(u16, u8), DayConfig>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct DayConfig {
+    /// Input of the day instead of the one following the pattern.
+    pub input: Option<PathBuf>,
+    /// Parameters of the puzzle, given to the solution of the day.
+    pub params: Params,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let inputs = InputLayout::default();
+        Self {
+            year: DEFAULT_YEAR,
+            inputs_dir: inputs.dir,
+            input_pattern: inputs.pattern,
+            examples_dir: PathBuf::from("examples"),
+            answers_path: PathBuf::from("answers/{year}.toml"),
+            format: Format::default(),
+            base_url: None,
+            session_file: PathBuf::from(".session"),
+            history_path: PathBuf::from("submissions.json"),
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+/// TOML keys are strings, parsed here into years and days.
+fn year_day_keys<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<BTreeMap<(u16, u8), DayConfig>, D::Error> {
+    let mut days = BTreeMap::new();
+    for (year, year_days) in
+        BTreeMap::<String, BTreeMap<String, DayConfig>>::deserialize(deserializer)?
+    {
+        let year = year
+            .parse()
+            .map_err(|_| de::Error::custom(format!("invalid year `{year}`")))?;
+        for (day, config) in year_days {
+            let day = day
+                .parse()
+                .map_err(|_| de::Error::custom(format!("invalid day `{day}`")))?;
+            days.insert((year, day), config);
+        }
+    }
+    Ok(days)
+}
+
+/// `advent_of_code_2023/config.toml` in `$XDG_CONFIG_HOME`, defaulting to `~/.config`.
+pub fn user_config_path() -> Option<PathBuf> {
+    let config_home = env::var_os("XDG_CONFIG_HOME")
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".config")))?;
+    Some(config_home.join("advent_of_code_2023").join("config.toml"))
+}
+
+impl Config {
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Reads `path` when given, else the first existing of the project and the user config
+    /// files, the default settings when there is none.
+    pub fn load(root: &Path, path: Option<&Path>) -> Result<Self, String> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => {
+                let candidates = [Some(root.join(PROJECT_FILE)), user_config_path()];
+                match candidates.into_iter().flatten().find(|p| p.exists()) {
+                    Some(path) => path,
+                    None => return Ok(Self::default()),
+                }
+            }
+        };
+        let content =
+            fs::read_to_string(&path).map_err(|e| format!("can't read {}: {e}", path.display()))?;
+        Self::from_toml(&content).map_err(|e| format!("invalid {}: {e}", path.display()))
+    }
+
+    pub fn inputs(&self) -> InputLayout {
+        InputLayout {
+            dir: self.inputs_dir.clone(),
+            pattern: self.input_pattern.clone(),
+            overrides: self
+                .days
+                .iter()
+                .filter_map(|(&key, config)| Some((key, config.input.clone()?)))
+                .collect(),
+        }
+    }
+
+    /// The parameters of the days of `year` setting some, only of `day` when given, rejecting
+    /// the ones their solution doesn't accept.
+    pub fn params(&self, year: u16, day: Option<u8>) -> Result<DaysParams, String> {
+        let days = match day {
+            Some(day) => (year, day)..=(year, day),
+            None => (year, 0)..=(year, u8::MAX),
+        };
+        let mut params = DaysParams::new();
+        for (&(year, day), config) in self.days.range(days) {
+            if config.params.is_empty() {
+                continue;
+            }
+            let solution = registry::find(year, day).ok_or_else(|| {
+                format!("parameters of day {day} of {year}, which has no solution")
+            })?;
+            solution
+                .check_params(&config.params)
+                .map_err(|e| format!("invalid parameters of day {day} of {year}: {e}"))?;
+            params.insert((year, day), config.params.clone());
+        }
+        Ok(params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn config_should_override_defaults() {
+        let config = Config::from_toml(
+            r#"
+year = 2022
+inputs_dir = "puzzles"
+input_pattern = "day_{day}.txt"
+format = "json"
+
+[days.2022.2]
+input = "other.txt"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.format, Format::Json);
+        assert_eq!(config.answers_path, Path::new("answers/{year}.toml"));
+        let inputs = config.inputs();
+        assert_eq!(inputs.path(2022, 1), Path::new("puzzles/day_1.txt"));
+        assert_eq!(inputs.path(2022, 2), Path::new("other.txt"));
+        assert_eq!(inputs.path(2023, 2), Path::new("puzzles/day_2.txt"));
+    }
+
+    #[test]
+    fn unknown_settings_should_be_rejected() {
+        let error = Config::from_toml("input_dir = \"puzzles\"").unwrap_err();
+        assert!(error.contains("unknown field `input_dir`"), "{error}");
+    }
+
+    #[test]
+    fn params_should_be_keyed_by_year_and_day() {
+        let config = Config::from_toml(
+            "[days.2023.2]\nparams = { bag = { red = 1, green = 2, blue = 3 } }\n[days.2023.3]\ninput = \"x\"",
+        )
+        .unwrap();
+        let params = config.params(DEFAULT_YEAR, None).unwrap();
+        assert_eq!(params.keys().collect::<Vec<_>>(), [&(DEFAULT_YEAR, 2)]);
+        assert_eq!(
+            params[&(DEFAULT_YEAR, 2)],
+            config.days[&(DEFAULT_YEAR, 2)].params
+        );
+    }
+
+    #[test]
+    fn days_settings_should_stay_with_their_year() {
+        let config = Config::from_toml(
+            "[days.2023.2]\ninput = \"games.txt\"\nparams = { bag = { red = 1, green = 2, blue = 3 } }",
+        )
+        .unwrap();
+        assert_eq!(config.params(2022, Some(2)).unwrap(), DaysParams::new());
+        assert_eq!(config.params(2022, None).unwrap(), DaysParams::new());
+        assert_eq!(config.params(2023, Some(2)).unwrap().len(), 1);
+        let inputs = config.inputs();
+        assert_eq!(inputs.path(2023, 2), Path::new("games.txt"));
+        assert_eq!(inputs.path(2022, 2), Path::new("inputs/2022/day_2.txt"));
+    }
+
+    #[test]
+    fn invalid_day_keys_should_be_rejected() {
+        let error = Config::from_toml("[days.2]\ninput = \"x\"").unwrap_err();
+        assert!(error.contains("invalid type"), "{error}");
+        let error = Config::from_toml("[days.2023.x]\ninput = \"x\"").unwrap_err();
+        assert!(error.contains("invalid day `x`"), "{error}");
+    }
+
+    #[test]
+    fn invalid_params_should_be_rejected() {
+        let config =
+            Config::from_toml("[days.2023.2]\nparams = { bag = { red = \"many\" } }").unwrap();
+        let error = config.params(2023, None).unwrap_err();
+        assert!(
+            error.starts_with("invalid parameters of day 2 of 2023: invalid parameter `bag`"),
+            "{error}"
+        );
+        let config = Config::from_toml("[days.2015.2]\nparams = { size = 7 }").unwrap();
+        assert_eq!(
+            config.params(2015, Some(2)).unwrap_err(),
+            "parameters of day 2 of 2015, which has no solution"
+        );
+        assert_eq!(config.params(2015, Some(3)).unwrap(), DaysParams::new());
+    }
+}
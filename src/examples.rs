@@ -0,0 +1,118 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::answers::Answers;
+
+/// A puzzle example, stored as `examples/<year>/day_<day>/example_<number>.txt`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub number: u8,
+    pub path: PathBuf,
+}
+
+pub fn day_dir(examples_dir: &Path, year: u16, day: u8) -> PathBuf {
+    examples_dir
+        .join(year.to_string())
+        .join(format!("day_{day}"))
+}
+
+/// The examples of `day` of `year`, sorted by number.
+pub fn examples(examples_dir: &Path, year: u16, day: u8) -> Vec<Example> {
+    let Ok(entries) = fs::read_dir(day_dir(examples_dir, year, day)) else {
+        return vec![];
+    };
+    let mut examples = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter_map(|path| {
+            let number = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix("example_")?
+                .strip_suffix(".txt")?
+                .parse()
+                .ok()?;
+            Some(Example {
+                year,
+                day,
+                number,
+                path,
+            })
+        })
+        .collect::<Vec<Example>>();
+    examples.sort_by_key(|e| e.number);
+    examples
+}
+
+/// Expected answers of the examples of `day` of `year`, read from
+/// `examples/<year>/day_<day>/answers.toml` and keyed by example number then part:
+///
+/// ```toml
+/// [1]
+/// 1 = 142
+/// ```
+pub fn expected_answers(examples_dir: &Path, year: u16, day: u8) -> Result<Answers, String> {
+    let path = day_dir(examples_dir, year, day).join("answers.toml");
+    match fs::read_to_string(&path) {
+        Ok(content) => Answers::from_toml(&content).map_err(|e| format!("{}: {e}", path.display())),
+        Err(_) => Ok(Answers::default()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{answer::Answer, input::InputSource, registry, runner::PARTS, solution::Params};
+
+    fn check_example(year: u16, day: u8, number: u8) {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let solution = registry::find(year, day).unwrap_or_else(|| {
+            panic!("no registered solution for the examples of day {day} of {year}")
+        });
+        let example = examples(&examples_dir, year, day)
+            .into_iter()
+            .find(|e| e.number == number)
+            .unwrap();
+        let input_content = InputSource::File(example.path.clone()).read().unwrap();
+        let answers = expected_answers(&examples_dir, year, day).unwrap();
+        let expected = PARTS
+            .iter()
+            .filter_map(|&part| Some((part, answers.get(number, part)?)))
+            .collect::<Vec<(u8, &Answer)>>();
+        assert!(
+            !expected.is_empty(),
+            "no expected answer for {}",
+            example.path.display()
+        );
+        for (part, answer) in expected {
+            let res = solution
+                .part(part, &input_content)
+                .unwrap_or_else(|| panic!("day {day} has no part {part}"))
+                .unwrap_or_else(|e| panic!("{}: {e}", example.path.display()));
+            assert_eq!(&res, answer, "part {part} of {}", example.path.display());
+            let parsed = solution
+                .parse(&input_content)
+                .unwrap_or_else(|e| panic!("{}: {e}", example.path.display()));
+            if let Some(res) = parsed.solve(part, &Params::new()) {
+                let res = res.unwrap_or_else(|e| panic!("{}: {e}", example.path.display()));
+                let path = example.path.display();
+                assert_eq!(&res, answer, "part {part} of parsed {path}");
+            }
+        }
+    }
+
+    include!(concat!(env!("OUT_DIR"), "/example_tests.rs"));
+
+    #[test]
+    fn examples_should_be_sorted_by_number() {
+        let examples_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("examples");
+        let numbers = examples(&examples_dir, 2023, 8)
+            .iter()
+            .map(|e| e.number)
+            .collect::<Vec<u8>>();
+        assert_eq!(numbers, [1, 2, 3]);
+    }
+}
@@ -0,0 +1,189 @@
+use std::{
+    fmt::Display,
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+/// Where a puzzle input is read from, `-` standing for the standard input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+}
+
+impl From<&Path> for InputSource {
+    fn from(value: &Path) -> Self {
+        if value == Path::new("-") {
+            Self::Stdin
+        } else {
+            Self::File(value.to_path_buf())
+        }
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl InputSource {
+    /// Reads the input, normalised by [`normalize`].
+    pub fn read(&self) -> Result<String, InputError> {
+        let res = match self {
+            InputSource::Stdin => {
+                let mut input_content = String::new();
+                io::stdin()
+                    .read_to_string(&mut input_content)
+                    .map(|_| input_content)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+        res.map(|input_content| normalize(&input_content))
+            .map_err(|error| InputError {
+                input: self.clone(),
+                error,
+            })
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub input: InputSource,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.error.kind() {
+            io::ErrorKind::NotFound => write!(f, "Input file {} doesn't exist", self.input),
+            _ => write!(f, "Can't read input {}: {}", self.input, self.error),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Uses `\n` line endings and removes the trailing whitespace of every line and the trailing
+/// blank lines.
+pub fn normalize(input_content: &str) -> String {
+    input_content
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end()
+        .to_string()
+}
+
+/// Removes the leading whitespace shared by every non-blank line, so lines indented with
+/// different characters keep their indentation.
+pub fn dedent(input_content: &str) -> String {
+    let indentation = input_content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| &l[..l.len() - l.trim_start().len()])
+        .reduce(common_prefix)
+        .unwrap_or("");
+    input_content
+        .lines()
+        .map(|l| l.strip_prefix(indentation).unwrap_or(l).trim_end())
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+fn common_prefix<'a>(left: &'a str, right: &str) -> &'a str {
+    let len = left
+        .char_indices()
+        .zip(right.chars())
+        .find(|((_, l), r)| l != r)
+        .map_or(left.len().min(right.len()), |((i, _), _)| i);
+    &left[..len]
+}
+
+/// Groups of lines separated by blank lines, without the empty groups.
+pub fn blocks(input_content: &str) -> Vec<Vec<&str>> {
+    let mut blocks = vec![vec![]];
+    for line in input_content.lines() {
+        if line.trim().is_empty() {
+            blocks.push(vec![]);
+        } else {
+            blocks.last_mut().unwrap().push(line);
+        }
+    }
+    blocks.retain(|b| !b.is_empty());
+    blocks
+}
+
+/// Items separated by `separator`, trimmed, ignoring line breaks and empty items such as the one
+/// after a trailing separator.
+pub fn items(input_content: &str, separator: char) -> impl Iterator<Item = &str> {
+    input_content
+        .split(separator)
+        .map(|i| i.trim_matches(|c| c == '\n' || c == '\r'))
+        .filter(|i| !i.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_should_be_stdin() {
+        assert_eq!(InputSource::from(Path::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from(Path::new("inputs/challenge_1.txt")),
+            InputSource::File(PathBuf::from("inputs/challenge_1.txt"))
+        );
+    }
+
+    #[test]
+    fn missing_file_should_be_reported() {
+        let error = InputSource::from(Path::new("does_not_exist.txt"))
+            .read()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Input file does_not_exist.txt doesn't exist"
+        );
+    }
+
+    #[test]
+    fn input_should_be_normalized() {
+        assert_eq!(normalize("a b \r\nc\t\r\n\r\n\n"), "a b\nc");
+    }
+
+    #[test]
+    fn common_indentation_should_be_removed() {
+        assert_eq!(dedent("    a\n\n      b\n    c"), "a\n\n  b\nc");
+    }
+
+    #[test]
+    fn blank_lines_shorter_than_indentation_should_be_kept() {
+        assert_eq!(dedent("    a\n  \n    b\n"), "a\n\nb");
+    }
+
+    #[test]
+    fn different_indentations_should_only_lose_common_prefix() {
+        assert_eq!(dedent("\t  a\n\t\tb"), "  a\n\tb");
+        assert_eq!(dedent("  a\n\u{3000}b"), "  a\n\u{3000}b");
+    }
+
+    #[test]
+    fn blocks_should_be_separated_by_blank_lines() {
+        assert_eq!(
+            blocks("a\nb\n\n\nc\n  \nd\n"),
+            vec![vec!["a", "b"], vec!["c"], vec!["d"]]
+        );
+    }
+
+    #[test]
+    fn items_should_ignore_line_breaks_and_trailing_separator() {
+        let items = items("rn=1,cm-,\nqp=3,\n", ',').collect::<Vec<&str>>();
+        assert_eq!(items, ["rn=1", "cm-", "qp=3"]);
+    }
+}
@@ -0,0 +1,44 @@
+//! Advent of Code solutions, in one module per year and registered as [`Solution`]s, and the
+//! grid utilities they share.
+//!
+//! The modules of the command line, which runs, checks, times and submits the solutions, are
+//! public for its binary only and hidden from the documentation: they aren't a stable API.
+pub mod answer;
+#[doc(hidden)]
+pub mod answers;
+#[doc(hidden)]
+pub mod bench;
+#[doc(hidden)]
+pub mod client;
+#[doc(hidden)]
+pub mod commands;
+#[doc(hidden)]
+pub mod config;
+#[doc(hidden)]
+pub mod examples;
+#[doc(hidden)]
+pub mod input;
+pub mod matrix;
+#[doc(hidden)]
+pub mod output;
+pub mod parse_error;
+pub mod point;
+pub mod registry;
+#[doc(hidden)]
+pub mod repl;
+#[doc(hidden)]
+pub mod runner;
+#[doc(hidden)]
+pub mod scaffold;
+pub mod solution;
+#[doc(hidden)]
+pub mod submit;
+#[doc(hidden)]
+pub mod trace;
+#[doc(hidden)]
+pub mod watch;
+pub mod year_2023;
+
+pub use answer::Answer;
+pub use parse_error::ParseError;
+pub use solution::Solution;
@@ -1,58 +1,274 @@
-use std::{collections::HashMap, env, fs};
+use std::{env, fs::File, num::NonZeroUsize, path::PathBuf, process::ExitCode};
+
+use advent_of_code_2023::{
+    answers, client, commands, config::{Config, DaysParams}, input::InputSource, output::Format,
+    registry::{self, PartKey},
+    trace,
+};
+use clap::{ArgAction, ArgGroup, Parser, Subcommand};
 
-use clap::Parser;
-mod challenge_1;
-mod challenge_15;
-mod challenge_2;
-mod challenge_3;
-mod challenge_4;
-mod challenge_5;
-mod challenge_6;
-mod challenge_8;
-mod matrix;
 #[derive(Parser)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+#[command(group(ArgGroup::new("every_part").args(["all", "verify"])))]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
     #[arg(short, long, default_value_t = 1)]
     step: u8,
-    #[arg(short, long)]
-    challenge_id: u8,
-    #[arg(short, long)]
-    file_path: Option<std::path::PathBuf>,
+    #[arg(short, long, required_unless_present_any = ["all", "verify"])]
+    challenge_id: Option<u8>,
+    /// Input files to run the step on, `-` reading the standard input
+    #[arg(short, long, num_args = 1..)]
+    file_path: Vec<PathBuf>,
+    /// Run every step of every registered challenge on its input
+    #[arg(short, long, conflicts_with_all = ["challenge_id", "file_path"])]
+    all: bool,
+    /// Run the step N times and report timing statistics instead of the result
+    #[arg(short, long, value_name = "N", conflicts_with = "all")]
+    bench: Option<u32>,
+    /// Check the computed answers against the answers file, of every challenge unless one is given
+    #[arg(long, conflicts_with_all = ["all", "bench", "file_path"])]
+    verify: bool,
+    /// Answers file checked by --verify, {year} standing for the year, answers/{year}.toml unless
+    /// configured
+    #[arg(long)]
+    answers_path: Option<PathBuf>,
+    /// Output format of the results, text unless configured
+    #[arg(long, value_enum, conflicts_with = "verify")]
+    format: Option<Format>,
+    /// Re-run the step whenever its input files or the day examples change
+    #[arg(short, long, conflicts_with_all = ["all", "bench", "verify", "format"])]
+    watch: bool,
+    /// Remove the indentation shared by the lines of the inputs, of pasted examples for instance
+    #[arg(long, conflicts_with_all = ["all", "verify", "watch"])]
+    dedent: bool,
+    /// Number of worker threads running the parts with --all or --verify
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        default_value = "1",
+        requires = "every_part"
+    )]
+    jobs: NonZeroUsize,
+    /// Write the diagnostics of the solutions, more of them each time the flag is repeated
+    #[arg(short = 'v', long = "trace", action = ArgAction::Count)]
+    trace: u8,
+    /// Only write the diagnostics of these days
+    #[arg(long, value_name = "DAY", num_args = 1.., requires = "trace")]
+    trace_days: Vec<u8>,
+    /// Write the diagnostics to this file instead of the standard error
+    #[arg(long, requires = "trace")]
+    trace_file: Option<PathBuf>,
+    /// Settings file, aoc.toml or the user config file by default
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+    /// Year of the calendar, 2023 unless configured
+    #[arg(short, long, global = true)]
+    year: Option<u16>,
+    /// Directory of the inputs, inputs unless configured
+    #[arg(long, global = true)]
+    inputs_dir: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download the puzzle input of a day into the inputs directory
+    Fetch {
+        #[arg(short, long)]
+        day: u8,
+        /// Overwrite the input file when it already exists
+        #[arg(long)]
+        force: bool,
+        /// Root URL of the website, defaults to $AOC_BASE_URL or https://adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+        /// File holding the session token when $AOC_SESSION isn't set, .session unless configured
+        #[arg(long)]
+        session_file: Option<PathBuf>,
+    },
+    /// Create the module of a new day from a template and register it, with an empty input
+    New {
+        #[arg(short, long)]
+        day: u8,
+    },
+    /// Explore the input of a day: parse it, run its parts and show parsed items
+    Repl {
+        #[arg(short, long)]
+        day: u8,
+        /// Input to explore instead of the day input
+        #[arg(short, long)]
+        file_path: Option<PathBuf>,
+    },
+    /// Submit the answer of a part, unless previous attempts already rule it out
+    Submit {
+        #[arg(short, long)]
+        day: u8,
+        #[arg(short, long, default_value_t = 1)]
+        part: u8,
+        /// Answer to submit, computed from the day input when omitted
+        #[arg(short, long)]
+        answer: Option<String>,
+        /// Root URL of the website, defaults to $AOC_BASE_URL or https://adventofcode.com
+        #[arg(long)]
+        base_url: Option<String>,
+        /// File holding the session token when $AOC_SESSION isn't set, .session unless configured
+        #[arg(long)]
+        session_file: Option<PathBuf>,
+        /// File recording every submitted answer and its verdict, submissions.json unless
+        /// configured
+        #[arg(long)]
+        history_path: Option<PathBuf>,
+    },
 }
 
-fn main() {
+fn main() -> ExitCode {
     let Cli {
+        command,
         step,
         challenge_id,
         file_path,
+        all,
+        bench,
+        verify,
+        answers_path,
+        format,
+        watch,
+        dedent,
+        jobs,
+        trace,
+        trace_days,
+        trace_file,
+        config,
+        year,
+        inputs_dir,
     } = Cli::parse();
-    let file_path = file_path.unwrap_or(
-        env::current_dir()
-            .unwrap()
-            .join(format!("inputs/challenge_{challenge_id}.txt")),
-    );
-    let input_content = fs::read_to_string(file_path).unwrap();
-    let challenges = HashMap::from([
-        ((1, 1), challenge_1::step_1 as fn(&str) -> String),
-        ((1, 2), challenge_1::step_2 as fn(&str) -> String),
-        ((2, 1), challenge_2::step_1 as fn(&str) -> String),
-        ((2, 2), challenge_2::step_2 as fn(&str) -> String),
-        ((3, 1), challenge_3::step_1 as fn(&str) -> String),
-        ((3, 2), challenge_3::step_2 as fn(&str) -> String),
-        ((4, 1), challenge_4::step_1 as fn(&str) -> String),
-        ((4, 2), challenge_4::step_2 as fn(&str) -> String),
-        ((5, 1), challenge_5::step_1 as fn(&str) -> String),
-        ((5, 2), challenge_5::step_2 as fn(&str) -> String),
-        ((6, 1), challenge_6::step_1 as fn(&str) -> String),
-        ((6, 2), challenge_6::step_2 as fn(&str) -> String),
-        ((8, 1), challenge_8::step as fn(&str) -> String),
-        ((15, 1), challenge_15::step_1 as fn(&str) -> String),
-    ]);
-
-    if let Some(step_handler) = challenges.get(&(challenge_id, step)) {
-        let res = step_handler(&input_content);
-        println!("Result for step {step} of challenge {challenge_id} is {res}")
+    let root = env::current_dir().unwrap();
+    let mut config = match Config::load(&root, config.as_deref()) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Some(year) = year {
+        config.year = year;
+    }
+    if let Some(inputs_dir) = inputs_dir {
+        config.inputs_dir = inputs_dir;
+    }
+    let year = config.year;
+    let params = match &command {
+        Some(Command::Fetch { .. } | Command::New { .. }) => Ok(DaysParams::new()),
+        Some(Command::Repl { day, .. } | Command::Submit { day, .. }) => {
+            config.params(year, Some(*day))
+        }
+        None => config.params(year, challenge_id),
+    };
+    let params = match params {
+        Ok(params) => params,
+        Err(error) => {
+            eprintln!("{error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let day_params = |day: u8| params.get(&(year, day)).cloned().unwrap_or_default();
+    let inputs = config.inputs();
+    let format = format.unwrap_or(config.format);
+    let log_file = match trace_file.map(File::create).transpose() {
+        Ok(log_file) => log_file,
+        Err(error) => {
+            eprintln!("Can't create trace file: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+    trace::init(trace::Config {
+        level: trace::Level::from_verbosity(trace),
+        days: trace_days,
+        log_file,
+    });
+    if let Some(command) = command {
+        return match command {
+            Command::Fetch {
+                day,
+                force,
+                base_url,
+                session_file,
+            } => commands::fetch(
+                year,
+                day,
+                force,
+                &base_url.unwrap_or_else(|| client::base_url(config.base_url.as_deref())),
+                &session_file.unwrap_or(config.session_file),
+                &inputs,
+            ),
+            Command::New { day } => {
+                commands::new_day(&root, &inputs, &config.examples_dir, year, day)
+            }
+            Command::Repl { day, file_path } => {
+                let path = file_path.unwrap_or_else(|| inputs.path(year, day));
+                commands::repl(
+                    year,
+                    day,
+                    InputSource::from(path.as_path()),
+                    &day_params(day),
+                )
+            }
+            Command::Submit {
+                day,
+                part,
+                answer,
+                base_url,
+                session_file,
+                history_path,
+            } => commands::submit(
+                PartKey { year, day, part },
+                answer,
+                &inputs,
+                &day_params(day),
+                &base_url.unwrap_or_else(|| client::base_url(config.base_url.as_deref())),
+                &session_file.unwrap_or(config.session_file),
+                &history_path.unwrap_or(config.history_path),
+            ),
+        };
+    }
+    if all {
+        return commands::run_all(year, &inputs, &params, format, jobs);
+    }
+    if verify {
+        let answers_path = answers_path.unwrap_or(config.answers_path);
+        let answers_path = answers::year_path(&answers_path, year);
+        return commands::verify(year, challenge_id, &inputs, &params, &answers_path, jobs);
+    }
+    let challenge_id = challenge_id.expect("challenge id is required without --all");
+    let Some(solution) = registry::find(year, challenge_id) else {
+        println!("Challenge {challenge_id} of {year} doesn't exists");
+        return ExitCode::FAILURE;
+    };
+    let sources = if file_path.is_empty() {
+        vec![InputSource::File(inputs.path(year, challenge_id))]
     } else {
-        println!("Challenge {challenge_id} or step {step} doesn't exists");
+        file_path
+            .iter()
+            .map(|p| InputSource::from(p.as_path()))
+            .collect()
+    };
+    if watch {
+        return commands::watch(
+            solution,
+            step,
+            sources,
+            &day_params(challenge_id),
+            &config.examples_dir,
+        );
     }
+    commands::run_steps(
+        solution,
+        step,
+        &sources,
+        &day_params(challenge_id),
+        bench,
+        dedent,
+        format,
+    )
 }
@@ -1,42 +1,670 @@
+use std::{
+    error::Error,
+    fmt::{Debug, Display},
+    mem, slice,
+};
 
-pub type Matrix<T> = Vec<Vec<T>>;
+use crate::point::{Direction, Point};
 
-#[derive(Debug)]
-pub struct MatrixWrapper<T>(Matrix<T>);
+/// What a neighbourhood does with the cells outside of the matrix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edge {
+    /// Skips them.
+    Clip,
+    /// Wraps around to the opposite side, as on a torus.
+    Wrap,
+    /// Yields them without a value.
+    Mark,
+}
+
+/// A row whose length differs from the one of the first row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RaggedRow {
+    /// 0-based index of the row.
+    pub row: usize,
+    pub expected: usize,
+    pub found: usize,
+}
+
+impl Display for RaggedRow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "row {} has {} cells instead of {}",
+            self.row, self.found, self.expected
+        )
+    }
+}
+
+impl Error for RaggedRow {}
+
+/// A rectangular grid, its cells stored row after row in a single vector.
+#[derive(Clone, PartialEq, Eq)]
+pub struct MatrixWrapper<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
 
 impl<T> MatrixWrapper<T> {
+    /// The matrix made of `rows`, which must all have the length of the first one.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, RaggedRow> {
+        let mut cells = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (row, values) in rows.into_iter().enumerate() {
+            if row == 0 {
+                width = values.len();
+            } else if values.len() != width {
+                return Err(RaggedRow {
+                    row,
+                    expected: width,
+                    found: values.len(),
+                });
+            }
+            cells.extend(values);
+            height += 1;
+        }
+        Ok(Self {
+            cells,
+            width,
+            height,
+        })
+    }
+    /// The matrix of rows of `width` cells taken in order from `cells`, whose length must be a
+    /// multiple of `width`.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Result<Self, RaggedRow> {
+        let len = cells.len();
+        match (width, len) {
+            (0, 0) => Ok(Self {
+                cells,
+                width,
+                height: 0,
+            }),
+            (0, _) => Err(RaggedRow {
+                row: 0,
+                expected: 0,
+                found: len,
+            }),
+            _ if !len.is_multiple_of(width) => Err(RaggedRow {
+                row: len / width,
+                expected: width,
+                found: len % width,
+            }),
+            _ => Ok(Self {
+                cells,
+                width,
+                height: len / width,
+            }),
+        }
+    }
+    /// A `width` by `height` matrix where every cell holds `value`.
+    pub fn filled(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![value; width * height],
+            width,
+            height,
+        }
+    }
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
     pub fn rows_len(&self) -> usize {
-        self.0.len()
+        self.height
     }
+    /// The number of columns, `None` when the matrix has no row.
     pub fn cols_len(&self) -> Option<usize> {
-        if self.0.len() > 0 {
-            Some(self.0[0].len())
+        (self.height > 0).then_some(self.width)
+    }
+    /// The cells with their point, row after row.
+    pub fn enumerate(&self) -> Enumerate<'_, T> {
+        Enumerate {
+            cells: self.cells.iter(),
+            point: Point::ORIGIN,
+            width: self.width as i32,
+        }
+    }
+    /// The index in `cells` of `point` when it's inside the matrix.
+    fn index(&self, point: Point) -> Option<usize> {
+        // Negative coordinates become too large to be inside.
+        let (row, col) = (point.row as usize, point.col as usize);
+        if row < self.height && col < self.width {
+            Some(row * self.width + col)
         } else {
             None
         }
     }
-    pub fn enumerate(&self) -> impl Iterator<Item = (i32, i32, &T)> {
-       self
-            .0
-            .iter()
-            .enumerate()
-            .flat_map(move|(i, row)| row.iter().enumerate().map(move |(j, col)| (i as i32, j as i32, col)))
+    pub fn at(&self, point: Point) -> Option<&T> {
+        self.index(point).map(|index| &self.cells[index])
     }
-    pub fn at(&self, row: i32, col: i32) -> Option<&T> {
-        if row < 0 || col < 0 {
+    pub fn at_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.index(point).map(|index| &mut self.cells[index])
+    }
+    /// Replaces the value at `point`, returning the previous one, `None` when the point is
+    /// outside of the matrix.
+    pub fn set(&mut self, point: Point, value: T) -> Option<T> {
+        self.at_mut(point).map(|cell| mem::replace(cell, value))
+    }
+    /// Swaps the values at `a` and `b`, returning whether both are inside the matrix.
+    pub fn swap(&mut self, a: Point, b: Point) -> bool {
+        let (Some(a), Some(b)) = (self.index(a), self.index(b)) else {
+            return false;
+        };
+        self.cells.swap(a, b);
+        true
+    }
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.cells.fill(value);
+    }
+    /// A matrix of the same size holding `f` of every value.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> MatrixWrapper<U> {
+        MatrixWrapper {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+    pub fn row_mut(&mut self, row: usize) -> Option<&mut [T]> {
+        if row >= self.height {
             return None;
         }
-        let (row, col) = (row as usize, col as usize);
-        if row < self.rows_len() && self.cols_len().is_some_and(|l| col < l) {
-            Some(&self.0[row][col])
-        } else {
-            None
+        Some(&mut self.cells[row * self.width..(row + 1) * self.width])
+    }
+    /// The values of the column `col`, from the first row to the last one.
+    pub fn col_mut(&mut self, col: usize) -> Option<impl Iterator<Item = &mut T>> {
+        if self.height == 0 || col >= self.width {
+            return None;
+        }
+        Some(self.cells.iter_mut().skip(col).step_by(self.width))
+    }
+    /// The cells at `offsets` from `center` with their value, in the order of the offsets, the
+    /// cells outside of the matrix being handled according to `edge`.
+    pub fn stencil<'a>(
+        &'a self,
+        center: Point,
+        offsets: impl IntoIterator<Item = Point> + 'a,
+        edge: Edge,
+    ) -> impl Iterator<Item = (Point, Option<&'a T>)> + 'a {
+        let (rows_len, cols_len) = (self.rows_len() as i32, self.cols_len().unwrap_or(0) as i32);
+        offsets.into_iter().filter_map(move |offset| {
+            let mut point = center + offset;
+            if edge == Edge::Wrap && rows_len > 0 && cols_len > 0 {
+                point = Point::new(
+                    point.row.rem_euclid(rows_len),
+                    point.col.rem_euclid(cols_len),
+                );
+            }
+            match (self.at(point), edge) {
+                (None, Edge::Clip | Edge::Wrap) => None,
+                (value, _) => Some((point, value)),
+            }
+        })
+    }
+    /// The cells sharing a side with `point` inside the matrix, clockwise from north.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        let offsets = Direction::CARDINALS.map(Direction::offset);
+        self.stencil(point, offsets, Edge::Clip)
+            .filter_map(|(point, value)| Some((point, value?)))
+    }
+    /// The cells sharing a side or a corner with `point` inside the matrix, clockwise from
+    /// north.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        let offsets = Direction::ALL.map(Direction::offset);
+        self.stencil(point, offsets, Edge::Clip)
+            .filter_map(|(point, value)| Some((point, value?)))
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| &self.cells[row * self.width..(row + 1) * self.width])
+    }
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        if row >= self.height {
+            return None;
+        }
+        Some(&self.cells[row * self.width..(row + 1) * self.width])
+    }
+    /// The columns, each from the first row to the last one.
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.cells.iter().skip(col).step_by(self.width))
+    }
+    pub fn col(&self, col: usize) -> Option<impl Iterator<Item = &T>> {
+        if self.height == 0 || col >= self.width {
+            return None;
         }
+        Some(self.cells.iter().skip(col).step_by(self.width))
+    }
+    /// A `width` by `height` matrix whose cells hold the values at `source` of their point.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(Point) -> Point) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height as i32)
+            .flat_map(|row| (0..width as i32).map(move |col| Point::new(row, col)))
+            .map(|point| self.cells[self.index(source(point)).unwrap()].clone())
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+    /// The matrix whose rows are the columns of this one.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |p| Point::new(p.col, p.row))
+    }
+    /// The matrix turned by `quarter_turns` quarters of a turn clockwise, counterclockwise when
+    /// negative.
+    pub fn rotate(&self, quarter_turns: i32) -> Self
+    where
+        T: Clone,
+    {
+        let (last_row, last_col) = (self.height as i32 - 1, self.width as i32 - 1);
+        match quarter_turns.rem_euclid(4) {
+            0 => self.clone(),
+            1 => self.remap(self.height, self.width, |p| {
+                Point::new(last_row - p.col, p.row)
+            }),
+            2 => self.remap(self.width, self.height, |p| {
+                Point::new(last_row - p.row, last_col - p.col)
+            }),
+            _ => self.remap(self.height, self.width, |p| {
+                Point::new(p.col, last_col - p.row)
+            }),
+        }
+    }
+    /// The matrix mirrored left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_col = self.width as i32 - 1;
+        self.remap(self.width, self.height, |p| {
+            Point::new(p.row, last_col - p.col)
+        })
+    }
+    /// The matrix mirrored top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let last_row = self.height as i32 - 1;
+        self.remap(self.width, self.height, |p| {
+            Point::new(last_row - p.row, p.col)
+        })
+    }
+    /// The `width` by `height` rectangle of cells starting at `top_left`, borrowed from the
+    /// matrix, `None` when it doesn't fit inside.
+    pub fn view(&self, top_left: Point, width: usize, height: usize) -> Option<MatrixView<'_, T>> {
+        let (row, col) = (
+            usize::try_from(top_left.row).ok()?,
+            usize::try_from(top_left.col).ok()?,
+        );
+        if row + height > self.height || col + width > self.width {
+            return None;
+        }
+        Some(MatrixView {
+            matrix: self,
+            top_left,
+            width,
+            height,
+        })
     }
 }
 
+/// Iterator of [`MatrixWrapper::enumerate`], walking the cells vector once and moving to the
+/// next row every `width` cells.
+#[derive(Debug, Clone)]
+pub struct Enumerate<'a, T> {
+    cells: slice::Iter<'a, T>,
+    point: Point,
+    width: i32,
+}
+
+impl<'a, T> Iterator for Enumerate<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let value = self.cells.next()?;
+        let point = self.point;
+        self.point.col += 1;
+        if self.point.col == self.width {
+            self.point = Point::new(point.row + 1, 0);
+        }
+        Some((point, value))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+    /// Walks the rest of the current row, then the next rows as slices.
+    fn fold<B, F: FnMut(B, Self::Item) -> B>(self, init: B, mut f: F) -> B {
+        let cells = self.cells.as_slice();
+        let width = self.width.max(1) as usize;
+        let (first, rest) = cells.split_at((width - self.point.col as usize).min(cells.len()));
+        let mut acc = first
+            .iter()
+            .zip(self.point.col..)
+            .fold(init, |acc, (value, col)| {
+                f(acc, (Point::new(self.point.row, col), value))
+            });
+        for (row, values) in (self.point.row + 1..).zip(rest.chunks(width)) {
+            acc = values.iter().zip(0..).fold(acc, |acc, (value, col)| {
+                f(acc, (Point::new(row, col), value))
+            });
+        }
+        acc
+    }
+}
+
+/// A rectangle of the cells of a [`MatrixWrapper`], its points relative to its top left cell.
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    matrix: &'a MatrixWrapper<T>,
+    top_left: Point,
+    width: usize,
+    height: usize,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    pub fn at(&self, point: Point) -> Option<&'a T> {
+        let inside = (0..self.height as i32).contains(&point.row)
+            && (0..self.width as i32).contains(&point.col);
+        if !inside {
+            return None;
+        }
+        self.matrix.at(self.top_left + point)
+    }
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        let col = self.top_left.col as usize;
+        self.matrix
+            .rows()
+            .skip(self.top_left.row as usize)
+            .take(self.height)
+            .map(move |row| &row[col..col + self.width])
+    }
+    pub fn enumerate(&self) -> impl Iterator<Item = (Point, &'a T)> + '_ {
+        self.rows().enumerate().flat_map(|(row, values)| {
+            values
+                .iter()
+                .enumerate()
+                .map(move |(col, value)| (Point::new(row as i32, col as i32), value))
+        })
+    }
+}
+
+/// Collects rows into a matrix, panicking on a ragged row: use [`MatrixWrapper::from_rows`] to
+/// handle them.
 impl<U> FromIterator<Vec<U>> for MatrixWrapper<U> {
     fn from_iter<T: IntoIterator<Item = Vec<U>>>(iter: T) -> Self {
-        Self(iter.into_iter().collect())
+        Self::from_rows(iter).unwrap_or_else(|e| panic!("{e}"))
+    }
+}
+
+/// Formats the matrix as its list of rows.
+impl<T: Debug> Debug for MatrixWrapper<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.cells.chunks(self.width.max(1)).collect::<Vec<&[T]>>();
+        f.debug_tuple("MatrixWrapper").field(&rows).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix() -> MatrixWrapper<char> {
+        MatrixWrapper::from_rows(["abc", "def", "ghi"].map(|l| l.chars().collect())).unwrap()
+    }
+
+    #[test]
+    fn constructors_should_reject_ragged_rows() {
+        let rows = vec![vec![1, 2], vec![3, 4], vec![5]];
+        assert_eq!(
+            MatrixWrapper::from_rows(rows).unwrap_err(),
+            RaggedRow {
+                row: 2,
+                expected: 2,
+                found: 1
+            }
+        );
+        let error = MatrixWrapper::from_cells(3, vec![1, 2, 3, 4]).unwrap_err();
+        assert_eq!(error.to_string(), "row 1 has 1 cells instead of 3");
+        let matrix = MatrixWrapper::from_cells(2, vec![1, 2, 3, 4, 5, 6]).unwrap();
+        assert_eq!((matrix.width(), matrix.height()), (2, 3));
+        assert_eq!(matrix.at(Point::new(2, 1)), Some(&6));
+        assert_eq!(
+            matrix,
+            MatrixWrapper::from_rows([vec![1, 2], vec![3, 4], vec![5, 6]]).unwrap()
+        );
+        let empty = MatrixWrapper::<u8>::from_rows([]).unwrap();
+        assert_eq!((empty.rows_len(), empty.cols_len()), (0, None));
+        assert_eq!(
+            MatrixWrapper::filled(2, 1, 'x'),
+            MatrixWrapper::from_cells(2, vec!['x'; 2]).unwrap()
+        );
+    }
+
+    #[test]
+    fn collected_rows_should_make_a_matrix() {
+        let matrix = ["abc", "def", "ghi"]
+            .iter()
+            .map(|l| l.chars().collect())
+            .collect::<MatrixWrapper<char>>();
+        assert_eq!(matrix, self::matrix());
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 has 1 cells instead of 2")]
+    fn collected_ragged_rows_should_panic() {
+        let _ = [vec![1, 2], vec![3]]
+            .into_iter()
+            .collect::<MatrixWrapper<u8>>();
+    }
+
+    #[test]
+    fn debug_should_show_rows() {
+        let matrix = MatrixWrapper::from_rows([vec![1, 2], vec![3, 4]]).unwrap();
+        assert_eq!(format!("{matrix:?}"), "MatrixWrapper([[1, 2], [3, 4]])");
+    }
+
+    #[test]
+    fn cells_should_be_addressed_by_point() {
+        let matrix = matrix();
+        assert_eq!(matrix.at(Point::new(1, 2)), Some(&'f'));
+        assert_eq!(matrix.at(Point::new(-1, 0)), None);
+        assert_eq!(matrix.at(Point::new(0, 3)), None);
+        assert_eq!(matrix.enumerate().nth(4), Some((Point::new(1, 1), &'e')));
+    }
+
+    #[test]
+    fn enumerate_should_resume_mid_row_when_folded() {
+        let matrix = matrix();
+        let mut cells = matrix.enumerate();
+        cells.nth(1);
+        let stepped = cells.clone().collect::<Vec<(Point, &char)>>();
+        let folded = cells.fold(vec![], |mut folded, cell| {
+            folded.push(cell);
+            folded
+        });
+        assert_eq!(folded, stepped);
+        assert_eq!(folded.len(), 7);
+        assert_eq!(folded[0], (Point::new(0, 2), &'c'));
+        assert_eq!(folded[1], (Point::new(1, 0), &'d'));
+    }
+
+    #[test]
+    fn cells_should_be_edited_in_place() {
+        let mut matrix = matrix();
+        *matrix.at_mut(Point::new(0, 0)).unwrap() = 'A';
+        assert_eq!(matrix.set(Point::new(2, 2), 'I'), Some('i'));
+        assert_eq!(matrix.set(Point::new(3, 0), 'x'), None);
+        assert!(matrix.swap(Point::new(0, 1), Point::new(2, 0)));
+        assert!(matrix.swap(Point::new(1, 2), Point::new(1, 0)));
+        assert!(!matrix.swap(Point::new(1, 2), Point::new(1, 3)));
+        let text =
+            |matrix: &MatrixWrapper<char>| matrix.enumerate().map(|(_, c)| *c).collect::<String>();
+        assert_eq!(text(&matrix), "AgcfedbhI");
+        matrix.fill('.');
+        assert_eq!(text(&matrix), ".........");
+    }
+
+    #[test]
+    fn rows_and_columns_should_be_editable() {
+        let mut matrix = matrix();
+        matrix.row_mut(1).unwrap().reverse();
+        for c in matrix.col_mut(2).unwrap() {
+            *c = c.to_ascii_uppercase();
+        }
+        assert!(matrix.row_mut(3).is_none());
+        assert!(matrix.col_mut(3).is_none());
+        let codes = matrix.map(|c| *c as u8 - b'A');
+        assert_eq!(
+            codes.enumerate().map(|(_, c)| *c).collect::<Vec<u8>>(),
+            [32, 33, 2, 37, 36, 3, 38, 39, 8]
+        );
+    }
+
+    #[test]
+    fn rows_and_columns_should_be_iterated() {
+        let matrix = matrix();
+        let rows = matrix.rows().collect::<Vec<&[char]>>();
+        assert_eq!(rows, [['a', 'b', 'c'], ['d', 'e', 'f'], ['g', 'h', 'i']]);
+        assert_eq!(matrix.row(1), Some(&['d', 'e', 'f'][..]));
+        assert_eq!(matrix.row(3), None);
+        let cols = matrix
+            .cols()
+            .map(|col| col.collect::<String>())
+            .collect::<Vec<String>>();
+        assert_eq!(cols, ["adg", "beh", "cfi"]);
+        assert_eq!(matrix.col(2).unwrap().collect::<String>(), "cfi");
+        assert!(matrix.col(3).is_none());
+    }
+
+    #[test]
+    fn transformations_should_move_cells() {
+        let matrix = MatrixWrapper::from_rows(["abc", "def"].map(|l| l.chars().collect())).unwrap();
+        let text = |matrix: MatrixWrapper<char>| {
+            matrix
+                .rows()
+                .map(|row| row.iter().collect::<String>())
+                .collect::<Vec<String>>()
+                .join("/")
+        };
+        assert_eq!(text(matrix.transpose()), "ad/be/cf");
+        assert_eq!(text(matrix.rotate(1)), "da/eb/fc");
+        assert_eq!(text(matrix.rotate(2)), "fed/cba");
+        assert_eq!(text(matrix.rotate(3)), "cf/be/ad");
+        assert_eq!(text(matrix.rotate(-1)), "cf/be/ad");
+        assert_eq!(text(matrix.rotate(4)), "abc/def");
+        assert_eq!(text(matrix.flip_horizontal()), "cba/fed");
+        assert_eq!(text(matrix.flip_vertical()), "def/abc");
+        assert_eq!(
+            matrix.rotate(1).rotate(1),
+            matrix.flip_horizontal().flip_vertical()
+        );
+    }
+
+    #[test]
+    fn views_should_borrow_a_rectangle() {
+        let matrix = matrix();
+        let view = matrix.view(Point::new(1, 1), 2, 2).unwrap();
+        assert_eq!((view.width(), view.height()), (2, 2));
+        assert_eq!(view.at(Point::new(0, 1)), Some(&'f'));
+        assert_eq!(view.at(Point::new(2, 0)), None);
+        assert_eq!(view.at(Point::new(-1, 0)), None);
+        assert_eq!(
+            view.enumerate().collect::<Vec<(Point, &char)>>(),
+            [
+                (Point::new(0, 0), &'e'),
+                (Point::new(0, 1), &'f'),
+                (Point::new(1, 0), &'h'),
+                (Point::new(1, 1), &'i')
+            ]
+        );
+        assert_eq!(
+            view.rows().collect::<Vec<&[char]>>(),
+            [['e', 'f'], ['h', 'i']]
+        );
+        assert!(matrix.view(Point::new(2, 0), 1, 2).is_none());
+        assert!(matrix.view(Point::new(0, -1), 1, 1).is_none());
+        assert_eq!(
+            matrix
+                .view(Point::ORIGIN, 0, 0)
+                .unwrap()
+                .enumerate()
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn neighbors4_should_clip_at_edges() {
+        let matrix = matrix();
+        let neighbors = matrix
+            .neighbors4(Point::new(0, 1))
+            .collect::<Vec<(Point, &char)>>();
+        assert_eq!(
+            neighbors,
+            [
+                (Point::new(0, 2), &'c'),
+                (Point::new(1, 1), &'e'),
+                (Point::new(0, 0), &'a')
+            ]
+        );
+        assert_eq!(matrix.neighbors4(Point::new(1, 1)).count(), 4);
+    }
+
+    #[test]
+    fn neighbors8_should_clip_at_edges() {
+        let matrix = matrix();
+        let values = |point| {
+            matrix
+                .neighbors8(point)
+                .map(|(_, v)| *v)
+                .collect::<String>()
+        };
+        assert_eq!(values(Point::new(2, 2)), "fhe");
+        assert_eq!(values(Point::new(1, 1)), "bcfihgda");
+    }
+
+    #[test]
+    fn stencil_should_wrap_or_mark_outside_cells() {
+        let matrix = matrix();
+        let offsets = [Point::new(-1, 0), Point::new(0, 3), Point::new(5, -4)];
+        let wrapped = matrix
+            .stencil(Point::ORIGIN, offsets, Edge::Wrap)
+            .collect::<Vec<(Point, Option<&char>)>>();
+        assert_eq!(
+            wrapped,
+            [
+                (Point::new(2, 0), Some(&'g')),
+                (Point::new(0, 0), Some(&'a')),
+                (Point::new(2, 2), Some(&'i'))
+            ]
+        );
+        let marked = matrix
+            .stencil(Point::ORIGIN, offsets, Edge::Mark)
+            .collect::<Vec<(Point, Option<&char>)>>();
+        assert_eq!(marked, offsets.map(|offset| (offset, None)));
+        assert_eq!(
+            matrix.stencil(Point::ORIGIN, offsets, Edge::Clip).count(),
+            0
+        );
     }
 }
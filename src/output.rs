@@ -0,0 +1,240 @@
+use std::io::{self, Stdout, Write};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    bench::Report,
+    input::InputSource,
+    runner::{Outcome, Run},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Human readable sentences and tables
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+    /// CSV rows preceded by a header
+    Csv,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RunRecord<'a> {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<&'a Answer>,
+    pub duration_ms: f64,
+    pub status: &'static str,
+    pub error: Option<String>,
+}
+
+/// The answer, the status and the error of an outcome.
+fn status(outcome: &Outcome) -> (Option<&Answer>, &'static str, Option<String>) {
+    match outcome {
+        Outcome::Solved(answer) => (Some(answer), "solved", None),
+        Outcome::Failed(error) => (None, "failed", Some(error.to_string())),
+        Outcome::MissingInput => (None, "missing_input", None),
+        Outcome::MissingPart => (None, "missing_part", None),
+    }
+}
+
+impl<'a> From<&'a Run> for RunRecord<'a> {
+    fn from(run: &'a Run) -> Self {
+        let (answer, status, error) = status(&run.outcome);
+        Self {
+            year: run.year,
+            day: run.day,
+            part: run.part,
+            input: run.input.to_string(),
+            answer,
+            duration_ms: run.elapsed.as_secs_f64() * 1000.0,
+            status,
+            error,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchRecord<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Option<&'a Answer>,
+    pub status: &'static str,
+    pub error: Option<String>,
+    pub iterations: u32,
+    pub parse_median_ms: Option<f64>,
+    pub solve_median_ms: Option<f64>,
+    pub min_ms: Option<f64>,
+    pub median_ms: Option<f64>,
+    pub mean_ms: Option<f64>,
+    pub max_ms: Option<f64>,
+}
+
+impl<'a> BenchRecord<'a> {
+    pub fn new(input: &InputSource, report: &'a Report) -> Self {
+        Self {
+            day: report.day,
+            part: report.part,
+            input: input.to_string(),
+            answer: Some(&report.answer),
+            status: "solved",
+            error: None,
+            iterations: report.iterations,
+            parse_median_ms: report
+                .parse
+                .as_ref()
+                .map(|s| s.median.as_secs_f64() * 1000.0),
+            solve_median_ms: report
+                .solve
+                .as_ref()
+                .map(|s| s.median.as_secs_f64() * 1000.0),
+            min_ms: Some(report.total.min.as_secs_f64() * 1000.0),
+            median_ms: Some(report.total.median.as_secs_f64() * 1000.0),
+            mean_ms: Some(report.total.mean.as_secs_f64() * 1000.0),
+            max_ms: Some(report.total.max.as_secs_f64() * 1000.0),
+        }
+    }
+
+    /// A benchmark that couldn't be timed because of `outcome`, with no durations.
+    pub fn unsolved(day: u8, part: u8, input: &InputSource, outcome: &'a Outcome) -> Self {
+        let (answer, status, error) = status(outcome);
+        Self {
+            day,
+            part,
+            input: input.to_string(),
+            answer,
+            status,
+            error,
+            iterations: 0,
+            parse_median_ms: None,
+            solve_median_ms: None,
+            min_ms: None,
+            median_ms: None,
+            mean_ms: None,
+            max_ms: None,
+        }
+    }
+}
+
+/// Writes records to `W` in a machine readable format, the CSV header being written once.
+pub enum RecordWriter<W: Write> {
+    Json(W),
+    Csv(Box<csv::Writer<W>>),
+}
+
+impl RecordWriter<Stdout> {
+    /// Returns `None` for the text format, which isn't record based.
+    pub fn stdout(format: Format) -> Option<Self> {
+        Self::new(format, io::stdout())
+    }
+}
+
+impl<W: Write> RecordWriter<W> {
+    pub fn new(format: Format, writer: W) -> Option<Self> {
+        match format {
+            Format::Text => None,
+            Format::Json => Some(Self::Json(writer)),
+            Format::Csv => Some(Self::Csv(Box::new(csv::Writer::from_writer(writer)))),
+        }
+    }
+    pub fn write<T: Serialize>(&mut self, record: &T) -> io::Result<()> {
+        match self {
+            Self::Json(writer) => {
+                serde_json::to_writer(&mut *writer, record)?;
+                writeln!(writer)
+            }
+            Self::Csv(writer) => {
+                writer.serialize(record)?;
+                writer.flush()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn run(outcome: Outcome) -> Run {
+        Run {
+            year: 2023,
+            day: 1,
+            part: 2,
+            input: InputSource::Stdin,
+            outcome,
+            elapsed: Duration::from_micros(1500),
+        }
+    }
+
+    #[test]
+    fn json_should_write_one_object_per_line() {
+        let mut output = vec![];
+        let mut writer = RecordWriter::new(Format::Json, &mut output).unwrap();
+        let (solved, missing) = (
+            run(Outcome::Solved(Answer::Unsigned(42))),
+            run(Outcome::MissingInput),
+        );
+        writer.write(&RunRecord::from(&solved)).unwrap();
+        writer.write(&RunRecord::from(&missing)).unwrap();
+        drop(writer);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            concat!(
+                r#"{"year":2023,"day":1,"part":2,"input":"stdin","answer":42,"duration_ms":1.5,"status":"solved","error":null}"#,
+                "\n",
+                r#"{"year":2023,"day":1,"part":2,"input":"stdin","answer":null,"duration_ms":1.5,"status":"missing_input","error":null}"#,
+                "\n"
+            )
+        );
+    }
+
+    #[test]
+    fn csv_should_write_header_once() {
+        let mut output = vec![];
+        let mut writer = RecordWriter::new(Format::Csv, &mut output).unwrap();
+        let (solved, missing) = (
+            run(Outcome::Solved(Answer::Text("abc".into()))),
+            run(Outcome::MissingPart),
+        );
+        writer.write(&RunRecord::from(&solved)).unwrap();
+        writer.write(&RunRecord::from(&missing)).unwrap();
+        drop(writer);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "year,day,part,input,answer,duration_ms,status,error\n\
+             2023,1,2,stdin,abc,1.5,solved,\n\
+             2023,1,2,stdin,,1.5,missing_part,\n"
+        );
+    }
+
+    #[test]
+    fn unsolved_bench_should_have_status_and_no_durations() {
+        let mut output = vec![];
+        let mut writer = RecordWriter::new(Format::Csv, &mut output).unwrap();
+        let outcome = Outcome::MissingPart;
+        writer
+            .write(&BenchRecord::unsolved(15, 2, &InputSource::Stdin, &outcome))
+            .unwrap();
+        drop(writer);
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "day,part,input,answer,status,error,iterations,parse_median_ms,solve_median_ms,\
+             min_ms,median_ms,mean_ms,max_ms\n\
+             15,2,stdin,,missing_part,,0,,,,,,\n"
+        );
+    }
+
+    #[test]
+    fn text_format_should_not_have_record_writer() {
+        assert!(RecordWriter::new(Format::Text, vec![]).is_none());
+    }
+}
@@ -0,0 +1,138 @@
+use std::{error::Error, fmt::Display};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Position {
+    /// 1-based line number in the puzzle input.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+}
+
+/// Error of a parser, pointing at the piece of the puzzle input it couldn't read.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub day: u8,
+    pub position: Option<Position>,
+    pub text: String,
+    pub reason: String,
+    source_line: Option<String>,
+    address: usize,
+}
+
+impl ParseError {
+    /// `text` should be a slice of the puzzle input so that its position can be resolved later
+    /// by [`ParseError::locate`].
+    pub fn new(text: &str, reason: impl Into<String>) -> Self {
+        Self {
+            day: 0,
+            position: None,
+            text: text.to_string(),
+            reason: reason.into(),
+            source_line: None,
+            address: text.as_ptr() as usize,
+        }
+    }
+    pub fn at(mut self, line: usize, column: usize) -> Self {
+        self.position = Some(Position { line, column });
+        self
+    }
+    /// Fills the day and, when `text` was sliced from `input_content`, the line and column of
+    /// the error.
+    pub fn locate(mut self, day: u8, input_content: &str) -> Self {
+        self.day = day;
+        let start = input_content.as_ptr() as usize;
+        let end = start + input_content.len();
+        if self.position.is_none() && self.address >= start && self.address + self.text.len() <= end
+        {
+            let offset = self.address - start;
+            let line_start = input_content[..offset].rfind('\n').map_or(0, |i| i + 1);
+            self.position = Some(Position {
+                line: input_content[..offset].matches('\n').count() + 1,
+                column: input_content[line_start..offset].chars().count() + 1,
+            });
+        }
+        if let Some(Position { line, .. }) = self.position {
+            self.source_line = input_content.lines().nth(line - 1).map(str::to_string);
+        }
+        self
+    }
+}
+
+/// Errors are equal when they say the same thing about the same place, whatever the buffer their
+/// text was sliced from.
+impl PartialEq for ParseError {
+    fn eq(&self, other: &Self) -> bool {
+        self.day == other.day
+            && self.position == other.position
+            && self.text == other.text
+            && self.reason == other.reason
+            && self.source_line == other.source_line
+    }
+}
+
+impl Eq for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {}", self.day)?;
+        if let Some(Position { line, column }) = self.position {
+            write!(f, ", line {line}, column {column}")?;
+        }
+        write!(f, ": {}", self.reason)?;
+        match (&self.position, &self.source_line) {
+            (Some(Position { line, column }), Some(source_line)) => {
+                let gutter = " ".repeat(line.to_string().len());
+                let underline = "^".repeat(self.text.chars().count().max(1));
+                write!(f, "\n{line} | {source_line}")?;
+                write!(f, "\n{gutter} | {}{underline}", " ".repeat(column - 1))
+            }
+            _ => write!(f, " `{}`", self.text),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locate_should_find_position_of_text_sliced_from_input() {
+        let input = "Game 1: 3 blue\nGame 2: 1 purple";
+        let error = ParseError::new(&input[25..], "unknown color").locate(2, input);
+        assert_eq!(
+            error.position,
+            Some(Position {
+                line: 2,
+                column: 11
+            })
+        );
+        assert_eq!(
+            error.to_string(),
+            "day 2, line 2, column 11: unknown color\n2 | Game 2: 1 purple\n  |           ^^^^^^"
+        );
+    }
+
+    #[test]
+    fn errors_should_be_equal_whatever_their_buffer() {
+        let (first, second) = (String::from("purple"), String::from("purple"));
+        assert_eq!(
+            ParseError::new(&first, "unknown color"),
+            ParseError::new(&second, "unknown color")
+        );
+        assert_ne!(
+            ParseError::new(&first, "unknown color"),
+            ParseError::new(&first, "unknown colour")
+        );
+    }
+
+    #[test]
+    fn locate_should_keep_unknown_position_of_text_not_sliced_from_input() {
+        let input = "Game 1: 3 blue";
+        let color = String::from("purple");
+        let error = ParseError::new(&color, "unknown color").locate(2, input);
+        assert_eq!(error.position, None);
+        assert_eq!(error.to_string(), "day 2: unknown color `purple`");
+    }
+}
@@ -0,0 +1,207 @@
+//! Coordinates in a grid, rows growing downwards and columns rightwards, and the directions to
+//! move between them.
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: i32, col: i32) -> Self {
+        Self { row, col }
+    }
+    /// Number of steps between the points moving along rows and columns only.
+    pub fn manhattan(self, other: Point) -> u32 {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+    /// Number of steps between the points moving diagonally too.
+    pub fn chebyshev(self, other: Point) -> u32 {
+        self.row
+            .abs_diff(other.row)
+            .max(self.col.abs_diff(other.col))
+    }
+    /// The point one step away in `direction`.
+    pub fn step(self, direction: Direction) -> Point {
+        self + direction.offset()
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+impl From<(i32, i32)> for Point {
+    fn from((row, col): (i32, i32)) -> Self {
+        Self::new(row, col)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Point) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Point {
+        self.step(rhs)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, rhs: Point) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Point) -> Point {
+        Point::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, rhs: Point) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<i32> for Point {
+    type Output = Point;
+
+    fn mul(self, rhs: i32) -> Point {
+        Point::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/// A compass direction, north pointing to the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::East,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::West,
+        Direction::NorthWest,
+    ];
+    /// North, east, south and west.
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::North => Point::new(-1, 0),
+            Direction::NorthEast => Point::new(-1, 1),
+            Direction::East => Point::new(0, 1),
+            Direction::SouthEast => Point::new(1, 1),
+            Direction::South => Point::new(1, 0),
+            Direction::SouthWest => Point::new(1, -1),
+            Direction::West => Point::new(0, -1),
+            Direction::NorthWest => Point::new(-1, -1),
+        }
+    }
+    /// The direction `eighths` eighths of a turn clockwise, counterclockwise when negative.
+    pub fn turn(self, eighths: i32) -> Direction {
+        Self::ALL[(self as i32 + eighths).rem_euclid(8) as usize]
+    }
+    /// The direction a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        self.turn(2)
+    }
+    /// The direction a quarter turn counterclockwise.
+    pub fn turn_left(self) -> Direction {
+        self.turn(-2)
+    }
+    pub fn opposite(self) -> Direction {
+        self.turn(4)
+    }
+    pub fn is_diagonal(self) -> bool {
+        self as u8 % 2 == 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_should_support_arithmetic() {
+        let (a, b) = (Point::new(1, 2), Point::new(3, -1));
+        assert_eq!(a + b, Point::new(4, 1));
+        assert_eq!(a - b, Point::new(-2, 3));
+        assert_eq!(-a, Point::new(-1, -2));
+        assert_eq!(a * 3, Point::new(3, 6));
+        let mut c = a;
+        c += b;
+        c -= Point::new(1, 1);
+        assert_eq!(c, Point::new(3, 0));
+        assert_eq!(Point::from((5, 6)).to_string(), "(5, 6)");
+    }
+
+    #[test]
+    fn distances_should_count_steps() {
+        let (a, b) = (Point::new(1, 2), Point::new(4, -2));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(a.chebyshev(b), 4);
+        assert_eq!(a.manhattan(a), 0);
+    }
+
+    #[test]
+    fn directions_should_turn_and_step() {
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::NorthWest.turn(1), Direction::North);
+        assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+        assert!(Direction::SouthWest.is_diagonal() && !Direction::West.is_diagonal());
+        assert_eq!(Point::ORIGIN + Direction::South, Point::new(1, 0));
+        assert_eq!(
+            Point::new(2, 2).step(Direction::NorthEast),
+            Point::new(1, 3)
+        );
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.offset() + d.opposite().offset() == Point::ORIGIN));
+    }
+}
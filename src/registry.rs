@@ -0,0 +1,88 @@
+use std::fmt::Display;
+
+use crate::solution::Solution;
+
+/// A part of the puzzle of a day of a year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PartKey {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+}
+
+impl Display for PartKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "day {} part {} of {}", self.day, self.part, self.year)
+    }
+}
+
+static SOLUTIONS: &[&dyn Solution] = &[
+    &crate::year_2023::challenge_1::Challenge,
+    &crate::year_2023::challenge_2::Challenge,
+    &crate::year_2023::challenge_3::Challenge,
+    &crate::year_2023::challenge_4::Challenge,
+    &crate::year_2023::challenge_5::Challenge,
+    &crate::year_2023::challenge_6::Challenge,
+    &crate::year_2023::challenge_8::Challenge,
+    &crate::year_2023::challenge_15::Challenge,
+];
+
+/// Every registered solution, ordered by year then day.
+pub fn solutions() -> &'static [&'static dyn Solution] {
+    SOLUTIONS
+}
+
+/// The registered solutions of `year`, ordered by day.
+pub fn year_solutions(year: u16) -> Vec<&'static dyn Solution> {
+    solutions()
+        .iter()
+        .copied()
+        .filter(|s| s.year() == year)
+        .collect()
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Solution> {
+    solutions()
+        .iter()
+        .copied()
+        .find(|s| (s.year(), s.day()) == (year, day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::DEFAULT_YEAR;
+
+    #[test]
+    fn solutions_should_be_ordered_by_unique_year_and_day() {
+        let keys = solutions()
+            .iter()
+            .map(|s| (s.year(), s.day()))
+            .collect::<Vec<(u16, u8)>>();
+        assert!(keys.windows(2).all(|w| w[0] < w[1]), "{keys:?}");
+    }
+
+    #[test]
+    fn find_should_return_solution_of_year_and_day() {
+        let solution = find(DEFAULT_YEAR, 6).unwrap();
+        assert_eq!((solution.year(), solution.day()), (2023, 6));
+        assert!(find(DEFAULT_YEAR, 0).is_none());
+        assert!(find(2015, 6).is_none());
+    }
+
+    #[test]
+    fn part_keys_should_be_ordered_by_year_then_day_then_part() {
+        let key = |year, day, part| PartKey { year, day, part };
+        assert!(key(2022, 25, 2) < key(2023, 1, 1));
+        assert!(key(2023, 1, 2) < key(2023, 2, 1));
+        assert_eq!(key(2023, 6, 2).to_string(), "day 6 part 2 of 2023");
+    }
+
+    #[test]
+    fn year_solutions_should_only_keep_the_year() {
+        let solutions = year_solutions(DEFAULT_YEAR);
+        assert!(solutions.iter().any(|s| s.day() == 6));
+        assert!(solutions.iter().all(|s| s.year() == DEFAULT_YEAR));
+        assert!(year_solutions(2015).is_empty());
+    }
+}
@@ -0,0 +1,278 @@
+//! Interactive exploration of a day: parsing its input, running its parts and showing items of
+//! the parsed structure without recompiling.
+use std::{
+    io::{self, BufRead, Write},
+    time::Instant,
+};
+
+use crate::{
+    input::InputSource,
+    runner,
+    solution::{Params, Solution},
+};
+
+pub const HELP: &str = "Commands:
+  parse          parse the input and print the parsed structure
+  run <part>     run a part on the input
+  show <path>    print an item of the parsed structure, e.g. `show maps.0.ranges` or
+                 `show 1.AAA`, fields and map keys by name, elements by index; the
+                 item is found in the pretty printed `Debug` output, so a custom `Debug`
+                 or a string looking like a field may make it miss or pick another item
+  reload         read the input again
+  help           print this help
+  quit           leave";
+
+/// An entry of a pretty printed `Debug` value: a field, a map entry or a sequence element.
+#[derive(Debug)]
+struct Entry {
+    key: Option<String>,
+    value: Vec<String>,
+}
+
+/// Splits `name: value` on the first `: ` outside of a string literal.
+fn split_key(line: &str) -> Option<(&str, &str)> {
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_string => escaped = true,
+            '"' => in_string = !in_string,
+            ':' if !in_string && line[i + 1..].starts_with(' ') => {
+                return Some((&line[..i], &line[i + 2..]))
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+impl Entry {
+    fn new(mut lines: Vec<String>) -> Self {
+        if let Some(last) = lines.last_mut() {
+            last.pop();
+        }
+        let split = lines.iter().enumerate().find_map(|(i, line)| {
+            if line.starts_with(' ') {
+                return None;
+            }
+            split_key(line).map(|(key, value)| (i, key.to_string(), value.to_string()))
+        });
+        match split {
+            Some((i, key, value)) => {
+                let mut key_lines = lines[..i].to_vec();
+                key_lines.push(key);
+                let mut value_lines = vec![value];
+                value_lines.extend_from_slice(&lines[i + 1..]);
+                Self {
+                    key: Some(key_lines.concat()),
+                    value: value_lines,
+                }
+            }
+            None => Self {
+                key: None,
+                value: lines,
+            },
+        }
+    }
+    /// Whether the entry is named `name`, map keys matching by their first string literal.
+    fn is_named(&self, name: &str) -> bool {
+        let Some(key) = &self.key else {
+            return false;
+        };
+        let literal = key.split('"').nth(1);
+        key.trim() == name || literal == Some(name)
+    }
+}
+
+/// The entries of a multi-line pretty printed value, its first and last lines opening and
+/// closing it.
+fn entries(value: &[String]) -> Vec<Entry> {
+    if value.len() < 3 {
+        return vec![];
+    }
+    let mut entries = vec![];
+    let mut current = vec![];
+    for line in &value[1..value.len() - 1] {
+        let line = line.get(4..).unwrap_or("").to_string();
+        let ends_entry = !line.starts_with(' ') && line.ends_with(',');
+        current.push(line);
+        if ends_entry {
+            entries.push(Entry::new(std::mem::take(&mut current)));
+        }
+    }
+    entries
+}
+
+/// The item at `path` in the `{:#?}` representation `debug`, each `.` separated segment
+/// selecting a field or map entry by name or an entry by index. This is a best-effort reading
+/// of the text, relying on the layout of the derived `Debug` implementations.
+pub fn select(debug: &str, path: &str) -> Option<String> {
+    let mut value = debug.lines().map(str::to_string).collect::<Vec<String>>();
+    for segment in path.split('.') {
+        let mut entries = entries(&value);
+        let position = entries
+            .iter()
+            .position(|e| e.is_named(segment))
+            .or_else(|| segment.parse().ok().filter(|&i| i < entries.len()))?;
+        value = entries.swap_remove(position).value;
+    }
+    Some(value.join("\n"))
+}
+
+pub struct Repl<'a> {
+    solution: &'a dyn Solution,
+    source: InputSource,
+    input_content: String,
+    params: Params,
+    /// `{:#?}` representation of the last parsed input.
+    parsed: Option<String>,
+}
+
+impl<'a> Repl<'a> {
+    pub fn new(
+        solution: &'a dyn Solution,
+        source: InputSource,
+        params: Params,
+    ) -> Result<Self, String> {
+        let input_content = source.read().map_err(|e| e.to_string())?;
+        Ok(Self {
+            solution,
+            source,
+            input_content,
+            params,
+            parsed: None,
+        })
+    }
+
+    fn parse(&mut self) -> Result<&str, String> {
+        if self.parsed.is_none() {
+            let parsed = self.solution.parse(&self.input_content).map_err(|e| {
+                e.locate(self.solution.day(), &self.input_content)
+                    .to_string()
+            })?;
+            self.parsed = Some(format!("{parsed:#?}"));
+        }
+        Ok(self.parsed.as_deref().unwrap_or_default())
+    }
+
+    /// Executes a command line, returning what to print.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let mut words = line.split_whitespace();
+        match (words.next(), words.next()) {
+            (Some("parse"), None) => {
+                self.parsed = None;
+                let start = Instant::now();
+                let parsed = self.parse()?.to_string();
+                Ok(format!("{parsed}\nparsed in {:?}", start.elapsed()))
+            }
+            (Some("run"), Some(part)) => {
+                let part = part.parse().map_err(|_| format!("invalid part `{part}`"))?;
+                let run = runner::run_part(
+                    self.solution,
+                    part,
+                    &self.source,
+                    &self.input_content,
+                    &self.params,
+                );
+                Ok(format!("{} in {:?}", run.outcome, run.elapsed))
+            }
+            (Some("show"), Some(path)) => {
+                select(self.parse()?, path).ok_or_else(|| format!("no item `{path}`"))
+            }
+            (Some("reload"), None) => {
+                self.input_content = self.source.read().map_err(|e| e.to_string())?;
+                self.parsed = None;
+                Ok(format!(
+                    "reloaded {} ({} lines)",
+                    self.source,
+                    self.input_content.lines().count()
+                ))
+            }
+            (Some("help"), None) => Ok(HELP.to_string()),
+            _ => Err(format!("unknown command `{line}`, try `help`")),
+        }
+    }
+
+    /// Reads commands from `input` until its end or `quit`.
+    pub fn run(mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        let prompt = format!("day {}> ", self.solution.day());
+        write!(output, "{prompt}")?;
+        output.flush()?;
+        for line in input.lines() {
+            let line = line?;
+            match line.trim() {
+                "quit" | "exit" => break,
+                "" => {}
+                line => match self.execute(line) {
+                    Ok(res) => writeln!(output, "{res}")?,
+                    Err(error) => writeln!(output, "error: {error}")?,
+                },
+            }
+            write!(output, "{prompt}")?;
+            output.flush()?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::{registry, solution::DEFAULT_YEAR};
+
+    fn example_repl(day: u8, example: u8) -> Repl<'static> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("examples")
+            .join(DEFAULT_YEAR.to_string())
+            .join(format!("day_{day}"))
+            .join(format!("example_{example}.txt"));
+        Repl::new(
+            registry::find(DEFAULT_YEAR, day).unwrap(),
+            InputSource::File(path),
+            Params::new(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn show_should_select_fields_elements_and_map_entries() {
+        let mut repl = example_repl(5, 1);
+        assert_eq!(repl.execute("show maps.0.destination").unwrap(), "\"soil\"");
+        assert_eq!(
+            repl.execute("show maps.6.ranges.1").unwrap(),
+            "Range {\n    destination_start: 56,\n    source_start: 93,\n    len: 4,\n}"
+        );
+        let mut repl = example_repl(8, 1);
+        assert_eq!(
+            repl.execute("show 1.AAA").unwrap(),
+            "NodeOptions(\n    Node(\n        \"BBB\",\n    ),\n    Node(\n        \"CCC\",\n    ),\n)"
+        );
+        assert_eq!(repl.execute("show 2").unwrap_err(), "no item `2`");
+    }
+
+    #[test]
+    fn commands_should_run_parts_and_report_errors() {
+        let mut repl = example_repl(6, 1);
+        assert!(repl.execute("run 1").unwrap().starts_with("288 in "));
+        assert!(repl.execute("parse").unwrap().starts_with("LeaderBoard(\n"));
+        assert_eq!(repl.execute("run x").unwrap_err(), "invalid part `x`");
+        assert_eq!(
+            repl.execute("jump").unwrap_err(),
+            "unknown command `jump`, try `help`"
+        );
+    }
+
+    #[test]
+    fn run_should_print_prompt_and_results() {
+        let repl = example_repl(6, 1);
+        let mut output = vec![];
+        repl.run("run 2\nquit\nrun 1\n".as_bytes(), &mut output)
+            .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("day 6> 71503 in "), "{output}");
+        assert!(output.ends_with("day 6> "), "{output}");
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    num::NonZeroUsize,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answer::Answer,
+    config::DaysParams,
+    input::InputSource,
+    parse_error::ParseError,
+    registry,
+    solution::{Params, Solution},
+};
+
+pub const PARTS: [u8; 2] = [1, 2];
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Solved(Answer),
+    Failed(ParseError),
+    MissingInput,
+    MissingPart,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Solved(answer) => write!(f, "{answer}"),
+            Outcome::Failed(error) => write!(f, "invalid input: {}", error.reason),
+            Outcome::MissingInput => write!(f, "missing input"),
+            Outcome::MissingPart => write!(f, "not solved yet"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: InputSource,
+    pub outcome: Outcome,
+    pub elapsed: Duration,
+}
+
+/// Where the puzzle inputs are: `dir` joined with `pattern`, `{year}` and `{day}` standing for
+/// the year and the day, unless the day has its own input in `overrides`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputLayout {
+    pub dir: PathBuf,
+    pub pattern: String,
+    pub overrides: BTreeMap<(u16, u8), PathBuf>,
+}
+
+impl Default for InputLayout {
+    fn default() -> Self {
+        Self::new("inputs")
+    }
+}
+
+impl InputLayout {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            pattern: "{year}/day_{day}.txt".to_string(),
+            overrides: BTreeMap::new(),
+        }
+    }
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        match self.overrides.get(&(year, day)) {
+            Some(path) => path.clone(),
+            None => self.dir.join(
+                self.pattern
+                    .replace("{year}", &year.to_string())
+                    .replace("{day}", &day.to_string()),
+            ),
+        }
+    }
+}
+
+pub fn run_part(
+    solution: &dyn Solution,
+    part: u8,
+    input: &InputSource,
+    input_content: &str,
+    params: &Params,
+) -> Run {
+    let start = Instant::now();
+    let outcome = match solution.part_with(part, input_content, params) {
+        Some(Ok(answer)) => Outcome::Solved(answer),
+        Some(Err(error)) => Outcome::Failed(error),
+        None => Outcome::MissingPart,
+    };
+    Run {
+        year: solution.year(),
+        day: solution.day(),
+        part,
+        input: input.clone(),
+        outcome,
+        elapsed: start.elapsed(),
+    }
+}
+
+/// Runs every part of every registered solution of `year` against its input.
+pub fn run_all(
+    year: u16,
+    inputs: &InputLayout,
+    params: &DaysParams,
+    jobs: NonZeroUsize,
+) -> Vec<Run> {
+    run_solutions(&registry::year_solutions(year), inputs, params, jobs)
+}
+
+/// Runs every part of `solutions` on `jobs` worker threads, returning the runs in the order of
+/// the solutions then of the parts whatever the order they completed in.
+pub fn run_solutions(
+    solutions: &[&dyn Solution],
+    inputs: &InputLayout,
+    params: &DaysParams,
+    jobs: NonZeroUsize,
+) -> Vec<Run> {
+    let no_params = Params::new();
+    let contents = solutions
+        .iter()
+        .map(|solution| {
+            let input = InputSource::File(inputs.path(solution.year(), solution.day()));
+            let input_content = input.read().ok();
+            (input, input_content)
+        })
+        .collect::<Vec<(InputSource, Option<String>)>>();
+    let tasks = solutions
+        .iter()
+        .zip(&contents)
+        .flat_map(|(&solution, input)| PARTS.map(|part| (solution, part, input)))
+        .collect::<Vec<(&dyn Solution, u8, &(InputSource, Option<String>))>>();
+    let next_task = AtomicUsize::new(0);
+    let runs = Mutex::new(Vec::with_capacity(tasks.len()));
+    thread::scope(|scope| {
+        for _ in 0..jobs.get().min(tasks.len()) {
+            scope.spawn(|| loop {
+                let index = next_task.fetch_add(1, Ordering::Relaxed);
+                let Some(&(solution, part, (input, input_content))) = tasks.get(index) else {
+                    break;
+                };
+                let run = match input_content {
+                    Some(input_content) => {
+                        let params = params
+                            .get(&(solution.year(), solution.day()))
+                            .unwrap_or(&no_params);
+                        run_part(solution, part, input, input_content, params)
+                    }
+                    None => Run {
+                        year: solution.year(),
+                        day: solution.day(),
+                        part,
+                        input: input.clone(),
+                        outcome: Outcome::MissingInput,
+                        elapsed: Duration::ZERO,
+                    },
+                };
+                runs.lock().unwrap().push((index, run));
+            });
+        }
+    });
+    let mut runs = runs.into_inner().unwrap();
+    runs.sort_by_key(|(index, _)| *index);
+    runs.into_iter().map(|(_, run)| run).collect()
+}
+
+pub fn print_table(runs: &[Run]) {
+    let answers = runs
+        .iter()
+        .map(|r| r.outcome.to_string())
+        .collect::<Vec<String>>();
+    let answer_width = answers
+        .iter()
+        .map(String::len)
+        .max()
+        .unwrap_or(0)
+        .max("answer".len());
+    let header = format!(
+        "{:>3} | {:>4} | {:<answer_width$} | elapsed",
+        "day", "part", "answer"
+    );
+    println!("{header}");
+    println!("{}", "-".repeat(header.len()));
+    for (run, answer) in runs.iter().zip(answers) {
+        println!(
+            "{:>3} | {:>4} | {:<answer_width$} | {:?}",
+            run.day, run.part, answer, run.elapsed
+        );
+    }
+    let missing_inputs = runs
+        .iter()
+        .filter(|r| r.part == PARTS[0] && r.outcome == Outcome::MissingInput)
+        .map(|r| r.day.to_string())
+        .collect::<Vec<String>>();
+    if !missing_inputs.is_empty() {
+        println!("Missing inputs for days: {}", missing_inputs.join(", "));
+    }
+    let missing_parts = runs
+        .iter()
+        .filter(|r| r.outcome == Outcome::MissingPart)
+        .map(|r| format!("{}.{}", r.day, r.part))
+        .collect::<Vec<String>>();
+    if !missing_parts.is_empty() {
+        println!("Missing steps: {}", missing_parts.join(", "));
+    }
+    for run in runs {
+        if let Outcome::Failed(error) = &run.outcome {
+            println!("\n{error}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+    use crate::solution::DEFAULT_YEAR;
+
+    #[test]
+    fn run_all_should_report_missing_inputs() {
+        let runs = run_all(
+            DEFAULT_YEAR,
+            &InputLayout::new("does_not_exist"),
+            &DaysParams::new(),
+            NonZeroUsize::MIN,
+        );
+        assert_eq!(
+            runs.len(),
+            registry::year_solutions(DEFAULT_YEAR).len() * PARTS.len()
+        );
+        assert!(runs.iter().all(|r| r.outcome == Outcome::MissingInput));
+    }
+
+    #[test]
+    fn run_part_should_report_missing_part() {
+        let day_15 = registry::find(DEFAULT_YEAR, 15).unwrap();
+        let run = run_part(day_15, 2, &InputSource::Stdin, "rn=1", &Params::new());
+        assert_eq!(run.outcome, Outcome::MissingPart);
+    }
+
+    #[test]
+    fn parallel_runs_should_keep_solutions_and_parts_order() {
+        let inputs = InputLayout::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"));
+        let solutions = [2, 4, 8, 15].map(|day| registry::find(DEFAULT_YEAR, day).unwrap());
+        let describe = |runs: Vec<Run>| {
+            runs.iter()
+                .map(|r| (r.day, r.part, r.outcome.to_string()))
+                .collect::<Vec<(u8, u8, String)>>()
+        };
+        let params = DaysParams::new();
+        let sequential = describe(run_solutions(
+            &solutions,
+            &inputs,
+            &params,
+            NonZeroUsize::MIN,
+        ));
+        let parallel = describe(run_solutions(
+            &solutions,
+            &inputs,
+            &params,
+            NonZeroUsize::new(3).unwrap(),
+        ));
+        assert_eq!(parallel, sequential);
+        assert_eq!(parallel[0].0, 2);
+        assert_eq!(parallel[7], (15, 2, "not solved yet".to_string()));
+    }
+
+    #[test]
+    fn input_layout_should_apply_pattern_unless_overridden() {
+        let mut inputs = InputLayout::new("inputs");
+        assert_eq!(inputs.path(2023, 1), Path::new("inputs/2023/day_1.txt"));
+        inputs.pattern = "challenge_{year}_{day}.txt".to_string();
+        inputs
+            .overrides
+            .insert((2023, 2), PathBuf::from("other.txt"));
+        assert_eq!(
+            inputs.path(2022, 1),
+            Path::new("inputs/challenge_2022_1.txt")
+        );
+        assert_eq!(inputs.path(2023, 2), Path::new("other.txt"));
+        assert_eq!(
+            inputs.path(2022, 2),
+            Path::new("inputs/challenge_2022_2.txt")
+        );
+    }
+}
@@ -0,0 +1,291 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::examples;
+
+const TEMPLATE: &str = include_str!("../templates/challenge.rs");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    AlreadyExists(PathBuf),
+    AlreadyRegistered(u16, u8),
+    /// The file doesn't have the lines new entries are inserted next to.
+    MissingAnchor(PathBuf),
+    Io(io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::AlreadyRegistered(year, day) => {
+                write!(f, "challenge {day} of {year} is already registered")
+            }
+            ScaffoldError::MissingAnchor(path) => {
+                write!(
+                    f,
+                    "can't find where to register the challenge in {}",
+                    path.display()
+                )
+            }
+            ScaffoldError::Io(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl From<io::Error> for ScaffoldError {
+    fn from(value: io::Error) -> Self {
+        Self::Io(value)
+    }
+}
+
+pub fn render_module(year: u16, day: u8) -> String {
+    TEMPLATE
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+}
+
+/// The expected answers of the empty first example, to be filled in with the puzzle ones.
+pub fn render_answers(year: u16, day: u8) -> String {
+    format!(
+        "# Expected answers of the examples of day {day} of {year}, keyed by example then part.\n\n[1]\n1 = 0\n"
+    )
+}
+
+/// Adds `pub mod <name>;` to the alphabetically sorted block of `pub mod` lines of `parent_rs`.
+fn declare_module(parent_rs: &str, name: &str) -> Option<String> {
+    let declaration = format!("pub mod {name};");
+    let lines = parent_rs.lines().collect::<Vec<&str>>();
+    let start = lines.iter().position(|l| l.starts_with("pub mod "))?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .take_while(|l| l.starts_with("pub mod "))
+            .count();
+    let mut modules = lines[start..end].to_vec();
+    modules.push(&declaration);
+    modules.sort_unstable_by_key(|l| l.trim_end_matches(';'));
+    let mut content = [&lines[..start], &modules, &lines[end..]]
+        .concat()
+        .join("\n");
+    content.push('\n');
+    Some(content)
+}
+
+/// The year and the day of a `SOLUTIONS` entry.
+fn registered(line: &str) -> Option<(u16, u8)> {
+    let (year, day) = line
+        .trim()
+        .strip_prefix("&crate::year_")?
+        .strip_suffix("::Challenge,")?
+        .split_once("::challenge_")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Inserts the challenge of `day` of `year` in the `SOLUTIONS` list, keeping it ordered by year
+/// then day.
+fn register_solution(registry_rs: &str, year: u16, day: u8) -> Option<String> {
+    let lines = registry_rs.lines().collect::<Vec<&str>>();
+    let last = lines.iter().rposition(|l| registered(l).is_some())?;
+    let index = lines
+        .iter()
+        .position(|l| registered(l).is_some_and(|key| key > (year, day)))
+        .unwrap_or(last + 1);
+    let entry = format!("    &crate::year_{year}::challenge_{day}::Challenge,");
+    let mut content = [&lines[..index], &[entry.as_str()], &lines[index..]]
+        .concat()
+        .join("\n");
+    content.push('\n');
+    Some(content)
+}
+
+/// Creates the module of `day` of `year` in the project at `root`, its empty input at
+/// `input_path` and an empty first example with its answers in `examples_dir`, and registers the
+/// module, creating the module of the year on its first day, returning the created files.
+/// Nothing is written when one of them already exists.
+pub fn scaffold(
+    root: &Path,
+    input_path: &Path,
+    examples_dir: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let src = root.join("src");
+    let year_module = format!("year_{year}");
+    let module_path = src.join(&year_module).join(format!("challenge_{day}.rs"));
+    let input_path = root.join(input_path);
+    let examples_path = examples::day_dir(&root.join(examples_dir), year, day);
+    let example_path = examples_path.join("example_1.txt");
+    let answers_path = examples_path.join("answers.toml");
+    for path in [&module_path, &input_path, &example_path, &answers_path] {
+        if path.exists() {
+            return Err(ScaffoldError::AlreadyExists(path.clone()));
+        }
+    }
+    let registry_path = src.join("registry.rs");
+    let registry_rs = fs::read_to_string(&registry_path)?;
+    if registry_rs
+        .lines()
+        .any(|l| registered(l) == Some((year, day)))
+    {
+        return Err(ScaffoldError::AlreadyRegistered(year, day));
+    }
+    let year_path = src.join(format!("{year_module}.rs"));
+    let lib_path = src.join("lib.rs");
+    let mut declarations = vec![];
+    if year_path.exists() {
+        let year_rs = declare_module(
+            &fs::read_to_string(&year_path)?,
+            &format!("challenge_{day}"),
+        )
+        .ok_or_else(|| ScaffoldError::MissingAnchor(year_path.clone()))?;
+        declarations.push((year_path.clone(), year_rs));
+    } else {
+        let lib_rs = declare_module(&fs::read_to_string(&lib_path)?, &year_module)
+            .ok_or_else(|| ScaffoldError::MissingAnchor(lib_path.clone()))?;
+        declarations.push((lib_path, lib_rs));
+        declarations.push((
+            year_path.clone(),
+            format!("//! Solutions of the {year} calendar.\npub mod challenge_{day};\n"),
+        ));
+    }
+    let registry_rs = register_solution(&registry_rs, year, day)
+        .ok_or_else(|| ScaffoldError::MissingAnchor(registry_path.clone()))?;
+    fs::create_dir_all(src.join(&year_module))?;
+    fs::write(&module_path, render_module(year, day))?;
+    if let Some(dir) = input_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(&input_path, "")?;
+    fs::create_dir_all(&examples_path)?;
+    fs::write(&example_path, "")?;
+    fs::write(&answers_path, render_answers(year, day))?;
+    for (path, content) in declarations {
+        fs::write(path, content)?;
+    }
+    fs::write(&registry_path, registry_rs)?;
+    Ok(vec![module_path, input_path, example_path, answers_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::answer::Answer;
+
+    #[test]
+    fn module_should_be_declared_in_sorted_order() {
+        let year_rs = "//! Solutions.\npub mod challenge_1;\npub mod challenge_2;\n";
+        assert_eq!(
+            declare_module(year_rs, "challenge_12").unwrap(),
+            "//! Solutions.\npub mod challenge_1;\npub mod challenge_12;\npub mod challenge_2;\n"
+        );
+        let lib_rs =
+            "//! Solutions.\npub mod watch;\npub mod year_2023;\n\npub use answer::Answer;\n";
+        assert_eq!(
+            declare_module(lib_rs, "year_2022").unwrap(),
+            "//! Solutions.\npub mod watch;\npub mod year_2022;\npub mod year_2023;\n\npub use answer::Answer;\n"
+        );
+    }
+
+    #[test]
+    fn solution_should_be_registered_by_year_and_day() {
+        let registry_rs = "static SOLUTIONS: &[&dyn Solution] = &[\n    &crate::year_2023::challenge_2::Challenge,\n    &crate::year_2023::challenge_15::Challenge,\n];\n";
+        assert_eq!(
+            register_solution(registry_rs, 2023, 9).unwrap(),
+            "static SOLUTIONS: &[&dyn Solution] = &[\n    &crate::year_2023::challenge_2::Challenge,\n    &crate::year_2023::challenge_9::Challenge,\n    &crate::year_2023::challenge_15::Challenge,\n];\n"
+        );
+        assert!(register_solution(registry_rs, 2023, 20).unwrap().contains(
+            "challenge_15::Challenge,\n    &crate::year_2023::challenge_20::Challenge,\n];"
+        ));
+        assert!(register_solution(registry_rs, 2022, 20).unwrap().contains(
+            "&[\n    &crate::year_2022::challenge_20::Challenge,\n    &crate::year_2023::challenge_2::"
+        ));
+    }
+
+    #[test]
+    fn scaffold_should_refuse_to_overwrite_existing_files() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        assert!(matches!(
+            scaffold(root, Path::new("inputs/2023/day_6.txt"), Path::new("examples"), 2023, 6),
+            Err(ScaffoldError::AlreadyExists(path)) if path.ends_with("src/year_2023/challenge_6.rs")
+        ));
+    }
+
+    #[test]
+    fn scaffold_should_create_the_module_of_a_new_year() {
+        let root = env::temp_dir().join(format!("aoc_scaffold_{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod registry;\npub mod year_2023;\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/registry.rs"),
+            "static SOLUTIONS: &[&dyn Solution] = &[\n    &crate::year_2023::challenge_1::Challenge,\n];\n",
+        )
+        .unwrap();
+        let examples_dir = Path::new("examples");
+        let created = scaffold(
+            &root,
+            Path::new("inputs/2022/day_3.txt"),
+            examples_dir,
+            2022,
+            3,
+        );
+        assert_eq!(
+            created.unwrap(),
+            [
+                root.join("src/year_2022/challenge_3.rs"),
+                root.join("inputs/2022/day_3.txt"),
+                root.join("examples/2022/day_3/example_1.txt"),
+                root.join("examples/2022/day_3/answers.toml"),
+            ]
+        );
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert_eq!(
+            read("src/lib.rs"),
+            "pub mod registry;\npub mod year_2022;\npub mod year_2023;\n"
+        );
+        assert_eq!(
+            read("src/year_2022.rs"),
+            "//! Solutions of the 2022 calendar.\npub mod challenge_3;\n"
+        );
+        assert!(
+            read("src/registry.rs").contains("&[\n    &crate::year_2022::challenge_3::Challenge,")
+        );
+        let answers = read("examples/2022/day_3/answers.toml");
+        let expected = examples::expected_answers(&root.join(examples_dir), 2022, 3).unwrap();
+        assert_eq!(expected.get(1, 1), Some(&Answer::from(0u64)), "{answers}");
+        assert!(matches!(
+            scaffold(
+                &root,
+                Path::new("inputs/2022/day_4.txt"),
+                examples_dir,
+                2022,
+                3
+            ),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        fs::remove_file(root.join("src/year_2022/challenge_3.rs")).unwrap();
+        assert!(matches!(
+            scaffold(&root, Path::new("inputs/2022/day_4.txt"), examples_dir, 2022, 3),
+            Err(ScaffoldError::AlreadyExists(path)) if path.ends_with("day_3/example_1.txt")
+        ));
+        fs::remove_dir_all(root).unwrap();
+    }
+
+    #[test]
+    fn rendered_module_should_use_year_and_day() {
+        let module = render_module(2022, 7);
+        assert!(module.contains("fn year(&self) -> u16 {\n        2022\n    }"));
+        assert!(module.contains("fn day(&self) -> u8 {\n        7\n    }"));
+        assert!(module.contains("#[cfg(test)]\nmod tests {"));
+        assert!(!module.contains("{{"));
+    }
+}
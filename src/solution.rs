@@ -0,0 +1,74 @@
+use std::fmt::Debug;
+
+use crate::{answer::Answer, parse_error::ParseError, trace};
+
+/// Year of the solutions that don't give theirs, and of the commands unless another is chosen.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Parameters of the puzzle of a day, read from its `params` table in the config file.
+pub type Params = toml::Table;
+
+/// An input parsed by [`Solution::parse`], able to solve the parts itself so that solving can be
+/// timed apart from parsing.
+pub trait Parsed: Debug {
+    /// Solves `part` on the parsed input, `None` when the part isn't solved on parsed inputs.
+    fn solve(&self, _part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        None
+    }
+}
+
+impl Parsed for Vec<&str> {}
+
+/// A day of the calendar, able to solve its puzzle input for each part.
+pub trait Solution: Sync {
+    fn year(&self) -> u16 {
+        DEFAULT_YEAR
+    }
+    fn day(&self) -> u8;
+    fn title(&self) -> &'static str;
+    /// Parses the input into the domain types the parts work on, so they can be inspected or
+    /// solved on their own.
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(input_content.lines().collect::<Vec<&str>>()))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError>;
+    /// Returns `None` when the second part has not been solved yet.
+    fn part_two(&self, _input_content: &str) -> Option<Result<Answer, ParseError>> {
+        None
+    }
+    /// Rejects the parameters the day doesn't read or can't deserialize, by default any.
+    fn check_params(&self, params: &Params) -> Result<(), String> {
+        match params.keys().next() {
+            Some(name) => Err(format!("unknown parameter `{name}`")),
+            None => Ok(()),
+        }
+    }
+    /// Solves the given part with `params`, to be overridden by the days reading parameters.
+    fn solve(
+        &self,
+        part: u8,
+        input_content: &str,
+        _params: &Params,
+    ) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(self.part_one(input_content)),
+            2 => self.part_two(input_content),
+            _ => None,
+        }
+    }
+    /// Runs the given part without parameters, see [`Solution::part_with`].
+    fn part(&self, part: u8, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        self.part_with(part, input_content, &Params::new())
+    }
+    /// Runs the given part with `params`, attributing its traces to the day and locating any
+    /// parse error in `input_content`.
+    fn part_with(
+        &self,
+        part: u8,
+        input_content: &str,
+        params: &Params,
+    ) -> Option<Result<Answer, ParseError>> {
+        let res = trace::in_day(self.day(), || self.solve(part, input_content, params));
+        res.map(|r| r.map_err(|e| e.locate(self.day(), input_content)))
+    }
+}
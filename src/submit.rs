@@ -0,0 +1,281 @@
+use std::{
+    fmt::Display,
+    fs, io,
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{answer::Answer, solution::DEFAULT_YEAR};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    RateLimited,
+    /// The part was already solved or isn't unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Verdict {
+    pub fn from_response(page: &str) -> Self {
+        if page.contains("That's the right answer") {
+            Verdict::Correct
+        } else if page.contains("You gave an answer too recently") {
+            Verdict::RateLimited
+        } else if page.contains("You don't seem to be solving the right level") {
+            Verdict::WrongLevel
+        } else if page.contains("That's not the right answer") {
+            if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            }
+        } else {
+            Verdict::Unknown
+        }
+    }
+    fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::RateLimited => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "already solved or locked part"),
+            Verdict::Unknown => write!(f, "unknown response"),
+        }
+    }
+}
+
+/// Text of the `<article>` of a response page, without its tags.
+pub fn response_message(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+    let mut message = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => message.push(c),
+            _ => {}
+        }
+    }
+    message.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    /// Histories written before years were recorded only hold attempts of the default year.
+    #[serde(default = "default_year")]
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch.
+    pub submitted_at: u64,
+}
+
+fn default_year() -> u16 {
+    DEFAULT_YEAR
+}
+
+impl Attempt {
+    pub fn new(year: u16, day: u8, part: u8, answer: &Answer, verdict: Verdict) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: answer.to_string(),
+            verdict,
+            submitted_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        }
+    }
+    fn answer(&self) -> Answer {
+        let Ok(answer) = self.answer.parse();
+        answer
+    }
+}
+
+/// Why an answer isn't worth submitting.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved(Answer),
+    KnownWrong(Verdict),
+    NotBelow(Answer),
+    NotAbove(Answer),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadySolved(answer) => write!(f, "already solved with {answer}"),
+            Refusal::KnownWrong(verdict) => write!(f, "already submitted, it was {verdict}"),
+            Refusal::NotBelow(answer) => write!(f, "{answer} was already too high"),
+            Refusal::NotAbove(answer) => write!(f, "{answer} was already too low"),
+        }
+    }
+}
+
+/// Every submitted answer, stored as JSON.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct History(Vec<Attempt>);
+
+impl History {
+    /// Reads the history at `path`, an empty one when the file doesn't exist yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Ok(serde_json::from_str(&content)?),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+    pub fn record(&mut self, attempt: Attempt) {
+        self.0.push(attempt);
+    }
+    /// Checks `answer` against the previous attempts of the part, including the bounds learnt
+    /// from too high and too low answers.
+    pub fn check(&self, year: u16, day: u8, part: u8, answer: &Answer) -> Result<(), Refusal> {
+        let attempts = self
+            .0
+            .iter()
+            .filter(|a| (a.year, a.day, a.part) == (year, day, part));
+        let mut lowest_too_high: Option<Answer> = None;
+        let mut highest_too_low: Option<Answer> = None;
+        for attempt in attempts {
+            let attempt_answer = attempt.answer();
+            match attempt.verdict {
+                Verdict::Correct => return Err(Refusal::AlreadySolved(attempt_answer)),
+                verdict if verdict.is_wrong() && attempt_answer == *answer => {
+                    return Err(Refusal::KnownWrong(verdict))
+                }
+                Verdict::TooHigh
+                    if lowest_too_high
+                        .as_ref()
+                        .is_none_or(|low| attempt_answer.as_integer() < low.as_integer()) =>
+                {
+                    lowest_too_high = Some(attempt_answer)
+                }
+                Verdict::TooLow
+                    if highest_too_low
+                        .as_ref()
+                        .is_none_or(|high| attempt_answer.as_integer() > high.as_integer()) =>
+                {
+                    highest_too_low = Some(attempt_answer)
+                }
+                _ => {}
+            }
+        }
+        let value = answer.as_integer();
+        match (value, lowest_too_high, highest_too_low) {
+            (Some(value), Some(too_high), _)
+                if too_high.as_integer().is_some_and(|h| value >= h) =>
+            {
+                Err(Refusal::NotBelow(too_high))
+            }
+            (Some(value), _, Some(too_low)) if too_low.as_integer().is_some_and(|l| value <= l) => {
+                Err(Refusal::NotAbove(too_low))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::{tests::serve_once, Client};
+
+    fn attempt(answer: u64, verdict: Verdict) -> Attempt {
+        Attempt::new(2023, 1, 1, &Answer::Unsigned(answer), verdict)
+    }
+
+    #[test]
+    fn verdict_should_be_parsed_from_response() {
+        let page =
+            "<article><p>That's not the right answer; your answer is too high.</p></article>";
+        assert_eq!(Verdict::from_response(page), Verdict::TooHigh);
+        assert_eq!(
+            response_message(page),
+            "That's not the right answer; your answer is too high."
+        );
+        assert_eq!(
+            Verdict::from_response("<p>You gave an answer too recently</p>"),
+            Verdict::RateLimited
+        );
+        assert_eq!(
+            Verdict::from_response("<p>That's the right answer!</p>"),
+            Verdict::Correct
+        );
+    }
+
+    #[test]
+    fn history_should_refuse_known_wrong_answers_and_learnt_bounds() {
+        let mut history = History::default();
+        history.record(attempt(100, Verdict::TooHigh));
+        history.record(attempt(10, Verdict::TooLow));
+        history.record(attempt(50, Verdict::Wrong));
+        history.record(attempt(60, Verdict::RateLimited));
+        let check = |answer| history.check(2023, 1, 1, &Answer::Unsigned(answer));
+        assert_eq!(check(50), Err(Refusal::KnownWrong(Verdict::Wrong)));
+        assert_eq!(check(120), Err(Refusal::NotBelow(Answer::Unsigned(100))));
+        assert_eq!(check(5), Err(Refusal::NotAbove(Answer::Unsigned(10))));
+        assert_eq!(check(60), Ok(()));
+        assert_eq!(history.check(2023, 1, 2, &Answer::Unsigned(120)), Ok(()));
+        assert_eq!(history.check(2022, 1, 1, &Answer::Unsigned(50)), Ok(()));
+    }
+
+    #[test]
+    fn history_should_refuse_solved_part() {
+        let mut history = History::default();
+        history.record(attempt(42, Verdict::Correct));
+        assert_eq!(
+            history.check(2023, 1, 1, &Answer::Unsigned(43)),
+            Err(Refusal::AlreadySolved(Answer::Unsigned(42)))
+        );
+    }
+
+    #[test]
+    fn history_without_years_should_be_of_default_year() {
+        let history: History = serde_json::from_str(
+            r#"[{"day": 1, "part": 1, "answer": "42", "verdict": "correct", "submitted_at": 0}]"#,
+        )
+        .unwrap();
+        assert_eq!(history.0[0].year, DEFAULT_YEAR);
+    }
+
+    #[test]
+    fn submit_should_post_level_and_answer() {
+        let (base_url, server) = serve_once(200, "<article>That's the right answer!</article>");
+        let page = Client::new(&base_url, "secret")
+            .submit_answer(2023, 6, 2, &Answer::Unsigned(71503))
+            .unwrap();
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2023/day/6/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=71503"));
+        assert_eq!(Verdict::from_response(&page), Verdict::Correct);
+    }
+}
@@ -0,0 +1,159 @@
+//! Leveled diagnostics of the solutions, off unless enabled, written to the standard error or
+//! to a log file. Traces are attributed to the day whose part is running on the current thread.
+use std::{
+    cell::Cell,
+    fmt::{self, Arguments, Display},
+    fs::File,
+    io::{self, Write},
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl Level {
+    /// The level enabled by `-v` repeated `count` times, `None` without it.
+    pub fn from_verbosity(count: u8) -> Option<Self> {
+        match count {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Info => write!(f, "INFO"),
+            Level::Debug => write!(f, "DEBUG"),
+            Level::Trace => write!(f, "TRACE"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Config {
+    /// Most verbose level written, nothing is written when `None`.
+    pub level: Option<Level>,
+    /// Days whose traces are written, every day when empty.
+    pub days: Vec<u8>,
+    /// Where the traces are written instead of the standard error.
+    pub log_file: Option<File>,
+}
+
+/// Most verbose enabled level, checked before taking the lock on the configuration.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+static CONFIG: RwLock<Config> = RwLock::new(Config {
+    level: None,
+    days: Vec::new(),
+    log_file: None,
+});
+
+thread_local! {
+    static DAY: Cell<Option<u8>> = const { Cell::new(None) };
+}
+
+pub fn init(config: Config) {
+    MAX_LEVEL.store(config.level.map_or(0, |l| l as u8), Ordering::Relaxed);
+    *CONFIG.write().unwrap() = config;
+}
+
+/// Runs `f` attributing its traces to `day`.
+pub fn in_day<R>(day: u8, f: impl FnOnce() -> R) -> R {
+    let previous = DAY.replace(Some(day));
+    let res = f();
+    DAY.set(previous);
+    res
+}
+
+pub fn enabled(level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    let config = CONFIG.read().unwrap();
+    config.level.is_some_and(|l| level <= l)
+        && (config.days.is_empty() || DAY.get().is_some_and(|d| config.days.contains(&d)))
+}
+
+fn format_line(level: Level, day: Option<u8>, message: Arguments) -> String {
+    match day {
+        Some(day) => format!("[day {day} {level}] {message}\n"),
+        None => format!("[{level}] {message}\n"),
+    }
+}
+
+/// Writes a trace, use the [`trace!`](crate::trace!) macro instead so the message is only
+/// formatted when the level is enabled.
+pub fn write(level: Level, message: Arguments) {
+    let line = format_line(level, DAY.get(), message);
+    let config = CONFIG.read().unwrap();
+    let _ = match config.log_file.as_ref() {
+        Some(mut file) => file.write_all(line.as_bytes()),
+        None => io::stderr().write_all(line.as_bytes()),
+    };
+}
+
+/// Writes a trace at the given [`Level`] when it's enabled for the running day:
+/// `trace!(Debug, "invalid number {}", value)`.
+#[macro_export]
+macro_rules! trace {
+    ($level:ident, $($arg:tt)+) => {
+        if $crate::trace::enabled($crate::trace::Level::$level) {
+            $crate::trace::write($crate::trace::Level::$level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs};
+
+    use super::*;
+
+    #[test]
+    fn line_should_name_day_and_level() {
+        assert_eq!(
+            format_line(
+                Level::Debug,
+                Some(5),
+                format_args!("map {}", "seed-to-soil")
+            ),
+            "[day 5 DEBUG] map seed-to-soil\n"
+        );
+        assert_eq!(
+            format_line(Level::Info, None, format_args!("x")),
+            "[INFO] x\n"
+        );
+    }
+
+    #[test]
+    fn traces_should_be_filtered_by_level_and_day() {
+        let path = env::temp_dir().join(format!("aoc_trace_{}.log", std::process::id()));
+        init(Config {
+            level: Some(Level::Debug),
+            days: vec![42],
+            log_file: Some(File::create(&path).unwrap()),
+        });
+        in_day(42, || {
+            trace!(Debug, "kept {}", 1);
+            trace!(Trace, "too verbose");
+        });
+        in_day(41, || trace!(Info, "other day"));
+        trace!(Info, "no day");
+        init(Config::default());
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "[day 42 DEBUG] kept 1\n"
+        );
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,199 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    answers::Answers,
+    examples,
+    input::InputSource,
+    runner::{self, Outcome, Run},
+    solution::{Params, Solution},
+};
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Modification time and length of a file, `None` when it can't be read.
+type Stamp = Option<(SystemTime, u64)>;
+
+fn stamp(path: &Path) -> Stamp {
+    let metadata = fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Re-runs a step whenever one of its inputs or examples changes.
+pub struct Watcher<'a> {
+    solution: &'a dyn Solution,
+    step: u8,
+    /// Parameters of the inputs, the examples being those of the puzzle.
+    params: Params,
+    inputs: Vec<PathBuf>,
+    examples_dir: PathBuf,
+    stamps: Vec<(PathBuf, Stamp)>,
+    answers: HashMap<PathBuf, String>,
+}
+
+impl<'a> Watcher<'a> {
+    pub fn new(
+        solution: &'a dyn Solution,
+        step: u8,
+        params: Params,
+        inputs: Vec<PathBuf>,
+        examples_dir: &Path,
+    ) -> Self {
+        Self {
+            solution,
+            step,
+            params,
+            inputs,
+            examples_dir: examples_dir.to_path_buf(),
+            stamps: vec![],
+            answers: HashMap::new(),
+        }
+    }
+
+    /// The inputs then the examples of the day, with their example number.
+    fn watched_paths(&self) -> Vec<(PathBuf, Option<u8>)> {
+        let mut paths = self
+            .inputs
+            .iter()
+            .map(|p| (p.clone(), None))
+            .collect::<Vec<(PathBuf, Option<u8>)>>();
+        paths.extend(
+            examples::examples(
+                &self.examples_dir,
+                self.solution.year(),
+                self.solution.day(),
+            )
+            .into_iter()
+            .map(|e| (e.path, Some(e.number))),
+        );
+        paths
+    }
+
+    /// Re-runs the step on every watched file when one of them, or the expected answers of the
+    /// examples, was added, modified or removed since the last poll, returning the lines to print.
+    pub fn poll(&mut self) -> Option<Vec<String>> {
+        let (year, day) = (self.solution.year(), self.solution.day());
+        let watched = self.watched_paths();
+        let answers_path = examples::day_dir(&self.examples_dir, year, day).join("answers.toml");
+        let stamps = watched
+            .iter()
+            .map(|(p, _)| p)
+            .chain([&answers_path])
+            .map(|p| (p.clone(), stamp(p)))
+            .collect::<Vec<(PathBuf, Stamp)>>();
+        if stamps == self.stamps {
+            return None;
+        }
+        self.stamps = stamps;
+        let mut lines = vec![format!(
+            "Step {} of challenge {day} ({}):",
+            self.step,
+            self.solution.title()
+        )];
+        let expected =
+            examples::expected_answers(&self.examples_dir, year, day).unwrap_or_else(|e| {
+                lines.push(format!("  {e}"));
+                Answers::default()
+            });
+        let no_params = Params::new();
+        for (path, example) in watched {
+            let source = InputSource::File(path.clone());
+            let params = match example {
+                Some(_) => &no_params,
+                None => &self.params,
+            };
+            let run = match source.read() {
+                Ok(input_content) => {
+                    runner::run_part(self.solution, self.step, &source, &input_content, params)
+                }
+                Err(_) => Run {
+                    year,
+                    day,
+                    part: self.step,
+                    input: source.clone(),
+                    outcome: Outcome::MissingInput,
+                    elapsed: Duration::ZERO,
+                },
+            };
+            let answer = run.outcome.to_string();
+            let change = match self.answers.get(&path) {
+                Some(previous) if *previous == answer => format!("{answer} (unchanged)"),
+                Some(previous) => format!("{previous} -> {answer}"),
+                None => answer.clone(),
+            };
+            let verdict = match (
+                example.and_then(|n| expected.get(n, self.step)),
+                &run.outcome,
+            ) {
+                (Some(expected), Outcome::Solved(actual)) if expected == actual => {
+                    " [ok]".to_string()
+                }
+                (Some(expected), _) => format!(" [expected {expected}]"),
+                (None, _) => String::new(),
+            };
+            lines.push(format!(
+                "  {source}: {change}{verdict} in {:?}",
+                run.elapsed
+            ));
+            self.answers.insert(path, answer);
+        }
+        Some(lines)
+    }
+
+    pub fn run(mut self) -> ! {
+        loop {
+            if let Some(lines) = self.poll() {
+                println!("{}", lines.join("\n"));
+            }
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::{registry, solution::DEFAULT_YEAR};
+
+    #[test]
+    fn poll_should_rerun_step_on_changes_only() {
+        let dir = env::temp_dir().join(format!("aoc_watch_{}", std::process::id()));
+        let examples_dir = dir.join("examples");
+        let day_dir = examples_dir.join("2023").join("day_6");
+        fs::create_dir_all(&day_dir).unwrap();
+        let input = dir.join("day_6.txt");
+        fs::write(&input, "Time: 7\nDistance: 9").unwrap();
+        let mut watcher = Watcher::new(
+            registry::find(DEFAULT_YEAR, 6).unwrap(),
+            1,
+            Params::new(),
+            vec![input.clone()],
+            &examples_dir,
+        );
+
+        let lines = watcher.poll().unwrap();
+        assert_eq!(lines[0], "Step 1 of challenge 6 (Wait For It):");
+        assert!(lines[1].starts_with(&format!("  {}: 4 in ", input.display())));
+        assert!(watcher.poll().is_none());
+
+        fs::write(&input, "Time: 7 15\nDistance: 9 40").unwrap();
+        let example = day_dir.join("example_1.txt");
+        fs::write(&example, "Time: 7\nDistance: 9").unwrap();
+        let lines = watcher.poll().unwrap();
+        assert!(lines[1].starts_with(&format!("  {}: 4 -> 32 in ", input.display())));
+        assert!(lines[2].starts_with(&format!("  {}: 4 in ", example.display())));
+
+        fs::write(day_dir.join("answers.toml"), "[1]\n1 = 5").unwrap();
+        let lines = watcher.poll().unwrap();
+        assert!(lines[1].contains(": 32 (unchanged) in "));
+        assert!(lines[2].contains(": 4 (unchanged) [expected 5] in "));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
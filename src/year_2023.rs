@@ -0,0 +1,9 @@
+//! Solutions of the 2023 calendar.
+pub mod challenge_1;
+pub mod challenge_15;
+pub mod challenge_2;
+pub mod challenge_3;
+pub mod challenge_4;
+pub mod challenge_5;
+pub mod challenge_6;
+pub mod challenge_8;
@@ -0,0 +1,130 @@
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+};
+
+const DIGITS: [DigitValue; 18] = [
+    DigitValue("one", 1),
+    DigitValue("two", 2),
+    DigitValue("three", 3),
+    DigitValue("four", 4),
+    DigitValue("five", 5),
+    DigitValue("six", 6),
+    DigitValue("seven", 7),
+    DigitValue("eight", 8),
+    DigitValue("nine", 9),
+    DigitValue("1", 1),
+    DigitValue("2", 2),
+    DigitValue("3", 3),
+    DigitValue("4", 4),
+    DigitValue("5", 5),
+    DigitValue("6", 6),
+    DigitValue("7", 7),
+    DigitValue("8", 8),
+    DigitValue("9", 9),
+];
+
+struct DigitValue(&'static str, u32);
+
+/// The lines of the calibration document.
+#[derive(Debug)]
+struct Document<'a>(Vec<&'a str>);
+
+impl<'a> From<&'a str> for Document<'a> {
+    fn from(value: &'a str) -> Self {
+        Self(input::items(value, '\n').collect())
+    }
+}
+
+impl Document<'_> {
+    /// Sums the calibration values of the lines, each one read by `line_value`.
+    fn calibration(
+        &self,
+        line_value: fn(&str) -> Result<u32, ParseError>,
+    ) -> Result<Answer, ParseError> {
+        let res = self
+            .0
+            .iter()
+            .map(|line| line_value(line))
+            .sum::<Result<u32, ParseError>>()?;
+        Ok(res.into())
+    }
+}
+
+impl Parsed for Document<'_> {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(self.calibration(get_line_value)),
+            2 => Some(self.calibration(get_line_digit)),
+            _ => None,
+        }
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        1
+    }
+    fn title(&self) -> &'static str {
+        "Trebuchet?!"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Document::from(input_content)))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+fn get_line_value(line: &str) -> Result<u32, ParseError> {
+    let first_digit = line.chars().find_map(|c| c.to_digit(10));
+    let last_digit = line.chars().rev().find_map(|c| c.to_digit(10));
+    match (first_digit, last_digit) {
+        (Some(first_digit), Some(last_digit)) => Ok(first_digit * 10 + last_digit),
+        _ => Err(ParseError::new(line, "line without digit")),
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    Document::from(input_content).calibration(get_line_value)
+}
+
+#[derive(Debug)]
+struct DigitPosition(usize, u32);
+
+fn get_line_digit(text: &str) -> Result<u32, ParseError> {
+    let mut res = DIGITS
+        .iter()
+        .flat_map(|digit| {
+            text.match_indices(digit.0)
+                .map(|(pos, _)| DigitPosition(pos, digit.1))
+        })
+        .collect::<Vec<DigitPosition>>();
+    res.sort_by_key(|d| d.0);
+    match (res.first(), res.last()) {
+        (Some(first_digit), Some(last_digit)) => Ok(first_digit.1 * 10 + last_digit.1),
+        _ => Err(ParseError::new(text, "line without digit nor spelled digit")),
+    }
+}
+
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    Document::from(input_content).calibration(get_line_digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_1_should_report_line_without_digit() {
+        let error = step_1("1abc2\npqrstu\n").unwrap_err();
+        assert_eq!(error.text, "pqrstu");
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+};
+
+const HASH_FACTOR: u32 = 17;
+const REMAINDER_DIVIDER: u32 = 256;
+/// A step of the initialization sequence.
+#[derive(Debug)]
+pub struct Step<'a>(pub &'a str);
+
+impl<'a> Step<'a> {
+    /// Result of the HASH algorithm on the step.
+    pub fn hash(&self) -> u32 {
+        let mut current_value = 0;
+        for c in self.0.chars() {
+            current_value += c as u32;
+            current_value *= HASH_FACTOR;
+            current_value %= REMAINDER_DIVIDER;
+        }
+        current_value
+    }
+}
+
+fn parse_steps(input_content: &str) -> Vec<Step<'_>> {
+    input::items(input_content, ',').map(Step).collect()
+}
+
+fn sum_hashes(steps: &[Step]) -> Answer {
+    steps.iter().map(Step::hash).sum::<u32>().into()
+}
+
+impl Parsed for Vec<Step<'_>> {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(Ok(sum_hashes(self))),
+            _ => None,
+        }
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        15
+    }
+    fn title(&self) -> &'static str {
+        "Lens Library"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(parse_steps(input_content)))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    Ok(sum_hashes(&parse_steps(input_content)))
+}
+#[cfg(test)]
+mod tests{
+    use crate::answer::Answer;
+    use crate::year_2023::challenge_15::step_1;
+
+    #[test]
+    fn should_return_valid_hash() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        assert_eq!(step_1(input).unwrap(), Answer::Unsigned(1320))
+    }
+
+    #[test]
+    fn trailing_newline_should_not_change_hash() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7\n";
+        assert_eq!(step_1(input).unwrap(), Answer::Unsigned(1320))
+    }
+    
+}
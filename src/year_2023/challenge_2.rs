@@ -0,0 +1,369 @@
+use std::fmt::Display;
+use std::iter::Sum;
+use std::ops::Add;
+
+use serde::Deserialize;
+
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+};
+const BAG: Set = Set {
+    blue: 14,
+    red: 12,
+    green: 13,
+};
+#[derive(Debug)]
+struct Game {
+    id: u16,
+    sets: Vec<Set>,
+}
+
+impl TryFrom<&str> for Game {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (game_id, game_content) = value
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(value, "missing `:` after game id"))?;
+        let (_, id) = game_id
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(game_id, "expected `Game <id>`"))?;
+        Ok(Self {
+            id: id
+                .parse()
+                .map_err(|_| ParseError::new(id, "invalid game id"))?,
+            sets: game_content
+                .split(';')
+                .map(Set::try_from)
+                .collect::<Result<Vec<Set>, ParseError>>()?,
+        })
+    }
+}
+
+impl Game {
+    fn is_valid(&self, set: &Set) -> bool {
+        self.sets.iter().all(|s| s.is_valid(set))
+    }
+    fn fewest_set_power(&self) -> u64 {
+        let mut result_set = self.sets[0].clone();
+        for set in self.sets.iter() {
+            result_set = result_set.add_max_color_from_other_set(set);
+        }
+        result_set.power()
+    }
+}
+impl<'a> Sum<&'a Game> for u64 {
+    fn sum<I: Iterator<Item = &'a Game>>(iter: I) -> Self {
+        iter.map(|g| u64::from(g.id)).sum()
+    }
+}
+#[derive(Debug, Hash, PartialEq, Eq)]
+enum Color {
+    Blue,
+    Red,
+    Green,
+}
+
+impl TryFrom<&str> for Color {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "blue" => Ok(Self::Blue),
+            "red" => Ok(Self::Red),
+            "green" => Ok(Self::Green),
+            color => Err(ParseError::new(color, "unknown color")),
+        }
+    }
+}
+#[derive(Debug)]
+struct CubeCount {
+    count: u16,
+    color: Color,
+}
+
+impl CubeCount {
+    fn new(count: u16, color: Color) -> Self {
+        Self { count, color }
+    }
+}
+
+impl TryFrom<&str> for CubeCount {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let (count, color) = value
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(value, "expected `<count> <color>`"))?;
+        Ok(Self {
+            count: count
+                .parse()
+                .map_err(|_| ParseError::new(count, "invalid cube count"))?,
+            color: Color::try_from(color)?,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Set {
+    red: u16,
+    green: u16,
+    blue: u16,
+}
+impl Set {
+    fn is_valid(&self, set: &Set) -> bool {
+        self.blue <= set.blue && self.green <= set.green && self.red <= set.red
+    }
+    fn power(&self) -> u64 {
+        u64::from(self.blue) * u64::from(self.green) * u64::from(self.red)
+    }
+    fn add_max_color_from_other_set(&self, other: &Set) -> Self {
+        let mut set = self.clone();
+        if self.blue < other.blue {
+            set.blue = other.blue
+        }
+        if set.red < other.red {
+            set.red = other.red
+        }
+        if set.green < other.green {
+            set.green = other.green
+        }
+        set
+    }
+}
+
+impl From<Set> for Vec<CubeCount> {
+    fn from(set: Set) -> Self {
+        [
+            CubeCount::new(set.blue, Color::Blue),
+            CubeCount::new(set.red, Color::Red),
+            CubeCount::new(set.green, Color::Green),
+        ]
+        .into_iter()
+        .filter(|c| c.count > 0)
+        .collect()
+    }
+}
+
+impl Display for Set {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "blue:{}, red:{}, green: {}",
+            self.blue, self.red, self.green
+        )
+    }
+}
+
+impl Add for Set {
+    type Output = Set;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            blue: self.blue + rhs.blue,
+            green: self.green + rhs.green,
+            red: self.red + rhs.red,
+        }
+    }
+}
+
+impl Sum for Set {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.reduce(Set::add).unwrap_or_default()
+    }
+}
+
+impl TryFrom<&str> for Set {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value
+            .trim()
+            .split(',')
+            .map(CubeCount::try_from)
+            .try_fold(Self::default(), |set, cube| {
+                let cube = cube?;
+                Ok(match cube.color {
+                    Color::Blue => Self {
+                        blue: set.blue + cube.count,
+                        ..set
+                    },
+                    Color::Red => Self {
+                        red: set.red + cube.count,
+                        ..set
+                    },
+                    Color::Green => Self {
+                        green: set.green + cube.count,
+                        ..set
+                    },
+                })
+            })
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        2
+    }
+    fn title(&self) -> &'static str {
+        "Cube Conundrum"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(parse_games(input_content)?))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+    fn check_params(&self, params: &Params) -> Result<(), String> {
+        if let Some(name) = params.keys().find(|&name| name != "bag") {
+            return Err(format!("unknown parameter `{name}`"));
+        }
+        bag(params).map(|_| ())
+    }
+    fn solve(
+        &self,
+        part: u8,
+        input_content: &str,
+        params: &Params,
+    ) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(
+                bag(params)
+                    .map_err(|e| ParseError::new("bag", e))
+                    .and_then(|bag| Ok(count_possible_games(&parse_games(input_content)?, &bag))),
+            ),
+            2 => self.part_two(input_content),
+            _ => None,
+        }
+    }
+}
+
+/// The `bag` parameter, the cubes of the puzzle when it isn't set.
+fn bag(params: &Params) -> Result<Set, String> {
+    match params.get("bag") {
+        Some(bag) => bag
+            .clone()
+            .try_into()
+            .map_err(|e| format!("invalid parameter `bag`: {e}")),
+        None => Ok(BAG),
+    }
+}
+
+fn parse_games(input_content: &str) -> Result<Vec<Game>, ParseError> {
+    input::items(input_content, '\n')
+        .map(Game::try_from)
+        .collect()
+}
+
+fn count_possible_games(games: &[Game], bag: &Set) -> Answer {
+    let res: u64 = games.iter().filter(|g| g.is_valid(bag)).sum();
+    res.into()
+}
+
+fn sum_fewest_set_powers(games: &[Game]) -> Answer {
+    let res: u64 = games.iter().map(|g| g.fewest_set_power()).sum();
+    res.into()
+}
+
+impl Parsed for Vec<Game> {
+    fn solve(&self, part: u8, params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(
+                bag(params)
+                    .map_err(|e| ParseError::new("bag", e))
+                    .map(|bag| count_possible_games(self, &bag)),
+            ),
+            2 => Some(Ok(sum_fewest_set_powers(self))),
+            _ => None,
+        }
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    Ok(count_possible_games(&parse_games(input_content)?, &BAG))
+}
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    Ok(sum_fewest_set_powers(&parse_games(input_content)?))
+}
+
+#[cfg(test)]
+mod tests{
+    use crate::answer::Answer;
+    use crate::solution::Solution;
+    use crate::year_2023::challenge_2::{step_1, Challenge, Set};
+
+
+    #[test]
+    pub fn set_should_be_valid_when_all_color_counts_are_bellow_bag_color_counts() {
+        let bag = Set {
+            blue: 19,
+            green: 18,
+            red: 16,
+        };
+        let valid_set = Set {
+            blue: 14,
+            green: 13,
+            red: 12,
+        };
+        assert!(
+            valid_set.is_valid(&bag),
+            "({valid_set}) hasn't valid colors counts for bag ({bag})"
+        )
+    }
+    #[test]
+    pub fn set_should_be_valid_when_one_color_count_is_above_bag_color_count() {
+        let bag = Set {
+            blue: 19,
+            green: 18,
+            red: 16,
+        };
+        let invalid_set = Set {
+            blue: 20,
+            green: 13,
+            red: 12,
+        };
+        assert!(
+            !invalid_set.is_valid(&bag),
+            "({invalid_set}) hasn't valid colors counts for bag ({bag})"
+        )
+    }
+
+    #[test]
+    pub fn step_1_should_report_unknown_color() {
+        let error = step_1("Game 1: 3 blue, 4 red\nGame 2: 1 purple; 2 green").unwrap_err();
+        assert_eq!(error.text, "purple");
+        assert_eq!(error.reason, "unknown color");
+    }
+
+    #[test]
+    pub fn part_one_should_use_the_bag_parameter() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 20 green";
+        assert_eq!(step_1(input).unwrap(), Answer::from(1u64));
+        let params = toml::from_str("bag = { red = 4, green = 20, blue = 3 }").unwrap();
+        assert_eq!(Challenge.check_params(&params), Ok(()));
+        let answer = Challenge.part_with(1, input, &params).unwrap().unwrap();
+        assert_eq!(answer, Answer::from(3u64));
+    }
+
+    #[test]
+    pub fn invalid_params_should_be_rejected() {
+        let missing_color = toml::from_str("bag = { red = 4, green = 20 }").unwrap();
+        let error = Challenge.check_params(&missing_color).unwrap_err();
+        assert!(error.starts_with("invalid parameter `bag`"), "{error}");
+        let unknown = toml::from_str("size = 4").unwrap();
+        assert_eq!(
+            Challenge.check_params(&unknown).unwrap_err(),
+            "unknown parameter `size`"
+        );
+    }
+}
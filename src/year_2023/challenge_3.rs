@@ -0,0 +1,252 @@
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+
+use std::vec;
+
+use crate::matrix::MatrixWrapper;
+use crate::point::Point;
+use crate::answer::Answer;
+use crate::input;
+use crate::parse_error::ParseError;
+use crate::solution::{Params, Parsed, Solution};
+use crate::trace;
+struct Number {
+    value: u32,
+    neighbors: HashSet<Neighbor>,
+}
+
+impl Number {
+    fn get_gears(&self) -> HashSet<&Neighbor> {
+        self.neighbors.iter().filter(|n| n.value == '*').collect()
+    }
+    fn new(digits: &[char], neighbors: &HashSet<Neighbor>) -> Result<Self, ParseError> {
+        let text = digits.iter().collect::<String>();
+        Ok(Self {
+            neighbors: neighbors.clone(),
+            value: text
+                .parse::<u32>()
+                .map_err(|_| ParseError::new(&text, "number too large"))?,
+        })
+    }
+    fn is_valid(&self) -> bool {
+        let is_valid = self.neighbors.iter().any(Neighbor::is_token);
+        if is_valid {
+            trace!(Trace, "valid number {}", self.value)
+        } else {
+            trace!(Debug, "invalid number {}", self.value)
+        }
+        is_valid
+    }
+}
+
+#[derive(Hash, PartialEq, Eq, Clone, Debug)]
+struct Neighbor {
+    value: char,
+    cell: Point,
+}
+
+impl Neighbor {
+    fn is_token(&self) -> bool {
+        !self.value.is_ascii_digit() && self.value != '.'
+    }
+}
+
+type Matrix = MatrixWrapper<char>;
+
+/// The engine schematic.
+#[derive(Debug)]
+struct Schematic(Matrix);
+
+impl Parsed for Schematic {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(sum_part_numbers(&self.0)),
+            2 => Some(sum_gear_ratios(&self.0)),
+            _ => None,
+        }
+    }
+}
+
+fn to_matrix(text: &str) -> Result<Matrix, ParseError> {
+    let lines = || input::items(text, '\n');
+    Matrix::from_rows(lines().map(|l| l.chars().collect::<Vec<char>>())).map_err(|e| {
+        let line = lines().nth(e.row).unwrap_or_default();
+        ParseError::new(line, e.to_string())
+    })
+}
+
+fn get_neighbors(matrix: &Matrix, cell: Point) -> HashSet<Neighbor> {
+    matrix
+        .neighbors8(cell)
+        .map(|(cell, value)| Neighbor {
+            cell,
+            value: *value,
+        })
+        .collect()
+}
+/// Turns the digits read from `start` into a number, leaving no digit behind.
+fn finish_number(
+    digits: &mut Vec<char>,
+    neighbors: &mut HashSet<Neighbor>,
+    start: Point,
+) -> Result<Option<Number>, ParseError> {
+    if digits.is_empty() {
+        return Ok(None);
+    }
+    let number = Number::new(digits, neighbors)
+        .map_err(|e| e.at(start.row as usize + 1, start.col as usize + 1))?;
+    digits.clear();
+    neighbors.clear();
+    Ok(Some(number))
+}
+fn read_numbers(matrix: &Matrix) -> Result<Vec<Number>, ParseError> {
+    let mut neighbors = HashSet::new();
+    let mut digits = vec![];
+    let mut start = Point::ORIGIN;
+    let mut numbers = vec![];
+    for (cell, c) in matrix.enumerate() {
+        if cell.col == 0 || !c.is_ascii_digit() {
+            numbers.extend(finish_number(&mut digits, &mut neighbors, start)?);
+        }
+        if c.is_ascii_digit() {
+            if digits.is_empty() {
+                start = cell;
+            }
+            digits.push(*c);
+            neighbors.extend(get_neighbors(matrix, cell));
+        }
+    }
+    numbers.extend(finish_number(&mut digits, &mut neighbors, start)?);
+    Ok(numbers)
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        3
+    }
+    fn title(&self) -> &'static str {
+        "Gear Ratios"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Schematic(to_matrix(input_content)?)))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    sum_part_numbers(&to_matrix(input_content)?)
+}
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    sum_gear_ratios(&to_matrix(input_content)?)
+}
+
+fn sum_part_numbers(matrix: &Matrix) -> Result<Answer, ParseError> {
+    let numbers = read_numbers(matrix)?;
+    Ok(numbers
+        .iter()
+        .filter(|&n| n.is_valid())
+        .map(|n| n.value)
+        .sum::<u32>()
+        .into())
+}
+fn sum_gear_ratios(matrix: &Matrix) -> Result<Answer, ParseError> {
+    let numbers = read_numbers(matrix)?;
+    let gears_ratio = numbers
+        .iter()
+        .filter(|&n| n.is_valid())
+        .fold(HashMap::new(), |mut numbers_by_gear, number| {
+            let gears = number.get_gears();
+            for gear in gears {
+                let  numbers = numbers_by_gear.entry(gear.cell).or_insert(Vec::new());
+                numbers.push(number.value);
+            }
+            numbers_by_gear
+        })
+        .iter()
+        .filter(|(_, v)| v.len() == 2)
+        .map(|(_, v)| v[0] * v[1])
+        .sum::<u32>();
+    Ok(gears_ratio.into())
+}
+#[cfg(test)]
+mod tests{
+    use std::collections::HashSet;
+
+    use crate::answer::Answer;
+    use crate::parse_error::Position;
+    use crate::point::Point;
+    use crate::year_2023::challenge_3::{to_matrix, get_neighbors, step_1, Neighbor};
+
+    #[test]
+    fn should_return_neighbors() {
+        let matrix = to_matrix(
+            r#".....
+.633.
+.#..."#,
+        )
+        .unwrap();
+        let result = get_neighbors(&matrix, Point::new(1, 1));
+        let expected_neighbors = HashSet::from([
+            Neighbor {
+                cell: Point::new(0, 0),
+                value: '.',
+            },
+            Neighbor {
+                cell: Point::new(0, 1),
+                value: '.',
+            },
+            Neighbor {
+                cell: Point::new(0, 2),
+                value: '.',
+            },
+            Neighbor {
+                cell: Point::new(1, 0),
+                value: '.',
+            },
+            Neighbor {
+                cell: Point::new(1, 2),
+                value: '3',
+            },
+            Neighbor {
+                cell: Point::new(2, 0),
+                value: '.',
+            },
+            Neighbor {
+                cell: Point::new(2, 1),
+                value: '#',
+            },
+            Neighbor {
+                cell: Point::new(2, 2),
+                value: '.',
+            },
+        ]);
+        assert_eq!(result, expected_neighbors);
+    }
+
+    #[test]
+    fn step_1_should_report_ragged_line() {
+        let error = step_1("467..\n...*.\n.35").unwrap_err();
+        assert_eq!(error.text, ".35");
+        assert_eq!(error.reason, "row 2 has 3 cells instead of 5");
+    }
+
+    #[test]
+    fn step_1_should_end_numbers_with_their_row() {
+        assert_eq!(step_1(".12\n3*.").unwrap(), Answer::from(15u32));
+        assert_eq!(step_1("*.\n.5").unwrap(), Answer::from(5u32));
+    }
+
+    #[test]
+    fn step_1_should_locate_too_large_number_ending_a_row() {
+        let error = step_1("..99999999999\n.............").unwrap_err();
+        assert_eq!(error.reason, "number too large");
+        assert_eq!(error.position, Some(Position { line: 1, column: 3 }));
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+    trace,
+};
+
+#[derive(Debug)]
+struct Card {
+    id: u16,
+    instance: u64,
+    winning_numbers: HashSet<u8>,
+    numbers_you_have: HashSet<u8>,
+}
+
+impl TryFrom<&str> for Card {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let (card, numbers) = value
+            .split_once(':')
+            .ok_or_else(|| ParseError::new(value, "missing `:` after card id"))?;
+        let id = card.trim().trim_start_matches("Card").trim();
+        let id = id
+            .parse()
+            .map_err(|_| ParseError::new(id, "invalid card id"))?;
+        let (winning_numbers, numbers_you_have) = numbers
+            .split_once('|')
+            .ok_or_else(|| ParseError::new(numbers, "missing `|` between numbers"))?;
+        let numbers_to_set = |txt_numbers: &str| -> Result<HashSet<u8>, ParseError> {
+            txt_numbers
+                .split_whitespace()
+                .map(|n| {
+                    n.parse::<u8>()
+                        .map_err(|_| ParseError::new(n, "invalid number"))
+                })
+                .collect()
+        };
+        Ok(Card {
+            id,
+            instance: 1,
+            numbers_you_have: numbers_to_set(numbers_you_have)?,
+            winning_numbers: numbers_to_set(winning_numbers)?,
+        })
+    }
+}
+impl Card {
+    fn matching_numbers(&self) -> u32 {
+        self.numbers_you_have
+            .intersection(&self.winning_numbers)
+            .count() as u32
+    }
+    fn value(&self) -> u32 {
+        let winning_matches = self.matching_numbers();
+        if winning_matches > 0 {
+            2u32.pow(
+                self.numbers_you_have
+                    .intersection(&self.winning_numbers)
+                    .count() as u32
+                    - 1,
+            )
+        } else {
+            0
+        }
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        4
+    }
+    fn title(&self) -> &'static str {
+        "Scratchcards"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(parse_cards(input_content)?))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+fn parse_cards(input_content: &str) -> Result<Vec<Card>, ParseError> {
+    input::items(input_content, '\n')
+        .map(Card::try_from)
+        .collect()
+}
+
+fn pile_value(cards: &[Card]) -> Answer {
+    cards.iter().map(Card::value).sum::<u32>().into()
+}
+
+/// Counts the cards once the copies they win are added to the pile, leaving `cards` untouched.
+fn count_cards(cards: &[Card]) -> Answer {
+    let mut instances = cards.iter().map(|c| c.instance).collect::<Vec<u64>>();
+    for i in 0..cards.len() {
+        let matching_numbers = cards[i].matching_numbers();
+        trace!(
+            Trace,
+            "card {} has {matching_numbers} matching numbers",
+            cards[i].id
+        );
+        if matching_numbers > 0 {
+            for j in i + 1..i + 1 + matching_numbers as usize {
+                if j < cards.len() {
+                    instances[j] += instances[i];
+                    trace!(Trace, "card {} has {} instances", cards[j].id, instances[j]);
+                }
+            }
+        }
+    }
+    instances.iter().sum::<u64>().into()
+}
+
+impl Parsed for Vec<Card> {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(Ok(pile_value(self))),
+            2 => Some(Ok(count_cards(self))),
+            _ => None,
+        }
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    Ok(pile_value(&parse_cards(input_content)?))
+}
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    Ok(count_cards(&parse_cards(input_content)?))
+}
+#[cfg(test)]
+mod tests {
+    use crate::answer::Answer;
+    use crate::year_2023::challenge_4::{step_1, step_2};
+
+    #[test]
+    fn step_1_should_report_invalid_number() {
+        let error = step_1("Card 1: 41 48 | 83 x6").unwrap_err();
+        assert_eq!(error.text, "x6");
+    }
+
+    #[test]
+    fn step_2_should_copy_the_cards_following_in_the_pile_whatever_their_ids() {
+        let input = "Card 3: 1 2 | 1 2\nCard 4: 1 | 1\nCard 5: 1 | 2";
+        assert_eq!(step_2(input).unwrap(), Answer::from(7u64));
+    }
+}
@@ -0,0 +1,515 @@
+use std::{
+    cell::RefCell,
+    fmt::{self, Debug, Display},
+    rc::Rc,
+    vec,
+};
+
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+    trace,
+};
+
+trait Seed: Debug {
+    fn match_range(&self, range: &Range) -> bool;
+    fn process_range(&mut self, range: &Range);
+    fn min(&self) -> u64;
+    /// Shrinks the seed to the part matching `range` and returns the parts left outside of it.
+    fn split_off(&mut self, _range: &Range) -> Vec<Rc<RefCell<dyn Seed>>> {
+        vec![]
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Seeds(Vec<Rc<RefCell<dyn Seed>>>);
+#[derive(Debug, Clone)]
+struct SimpleSeed(u64);
+
+impl Seed for SimpleSeed {
+    fn match_range(&self, range: &Range) -> bool {
+        self.0 >= range.source_start && self.0 < range.source_end()
+    }
+
+    fn process_range(&mut self, range: &Range) {
+        let diff = self.0 - range.source_start;
+        self.0 = range.destination_start + diff
+    }
+
+    fn min(&self) -> u64 {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct RangedSeed {
+    id: u8,
+    min: u64,
+    max: u64,
+}
+
+impl fmt::Display for RangedSeed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "id: {}, min: {}, max: {}",self.id, self.min, self.max)
+    }
+}
+impl RangedSeed {
+    fn get_intersection(&self, range: (u64, u64)) -> Self {
+        Self {
+            id: self.id,
+            min: self.min.max(range.0),
+            max: self.max.min(range.1),
+        }
+    }
+}
+impl Seed for RangedSeed {
+    fn match_range(&self, range: &Range) -> bool {
+        let (start, end) = (range.source_start, range.source_end());
+        let res = self.min < end && self.max > start;
+        if res {
+            trace!(Trace, "seed {} matches range {}", self, range);
+        }
+        res
+    }
+
+    fn process_range(&mut self, range: &Range) {
+        let (src_start, src_end, dst_start, dst_end) = (
+            range.source_start,
+            range.source_end(),
+            range.destination_start,
+            range.destination_end(),
+        );
+        let intersection = self.get_intersection((src_start, src_end));
+        self.min = dst_start + intersection.min - src_start;
+        self.max = dst_end + intersection.max - src_end;
+        trace!(Trace, "processed seed {}", self);
+    }
+
+    fn min(&self) -> u64 {
+        self.min
+    }
+
+    fn split_off(&mut self, range: &Range) -> Vec<Rc<RefCell<dyn Seed>>> {
+        let (start, end) = (range.source_start, range.source_end());
+        let mut outside_parts: Vec<Rc<RefCell<dyn Seed>>> = vec![];
+        if self.min < start {
+            outside_parts.push(Rc::new(RefCell::new(Self {
+                id: self.id,
+                min: self.min,
+                max: start,
+            })));
+        }
+        if self.max > end {
+            outside_parts.push(Rc::new(RefCell::new(Self {
+                id: self.id,
+                min: end,
+                max: self.max,
+            })));
+        }
+        *self = self.get_intersection((start, end));
+        outside_parts
+    }
+}
+
+/// The numbers of the seeds line, and the text they were read from.
+fn parse_seeds(value: &str) -> Result<(Vec<u64>, &str), ParseError> {
+    let (_, seeds_text) = value
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(value, "expected `seeds: <seed>...`"))?;
+    let seeds = seeds_text
+        .split_whitespace()
+        .map(|seed| {
+            seed.parse::<u64>()
+                .map_err(|_| ParseError::new(seed, "invalid seed"))
+        })
+        .collect::<Result<Vec<u64>, ParseError>>()?;
+    if seeds.is_empty() {
+        return Err(ParseError::new(value, "no seeds"));
+    }
+    Ok((seeds, seeds_text))
+}
+
+impl Seeds {
+    fn new(seeds: &[u64], seeds_text: &str, use_ranged_seeds: &bool) -> Result<Self, ParseError> {
+        if *use_ranged_seeds {
+            if !seeds.len().is_multiple_of(2) {
+                return Err(ParseError::new(
+                    seeds_text,
+                    "seed ranges must come in pairs",
+                ));
+            }
+            Ok(Seeds(Self::to_ranged_seeds(seeds)))
+        } else {
+            Ok(Seeds(
+                seeds
+                    .iter()
+                    .map(|&s| -> Rc<RefCell<dyn Seed>> { Rc::new(RefCell::new(SimpleSeed(s))) })
+                    .collect(),
+            ))
+        }
+    }
+    fn to_ranged_seeds(value: &[u64]) -> Vec<Rc<RefCell<dyn Seed>>> {
+        let mut ranged_seeds: Vec<Rc<RefCell<dyn Seed>>> = vec![];
+        for i in 0..value.len() - 1 {
+            if i % 2 == 0 {
+                ranged_seeds.push(Rc::new(RefCell::new(RangedSeed {
+                    id: (ranged_seeds.len() + 1) as u8,
+                    min: value[i],
+                    max: value[i] + value[i + 1],
+                })));
+            }
+        }
+        ranged_seeds
+    }
+    fn lowest(&self) -> u64 {
+        self.0.iter().map(|s| s.borrow().min()).min().unwrap()
+    }
+}
+
+#[derive(Debug)]
+struct Almanac<'a> {
+    /// The seed numbers, read as seeds or as seed ranges depending on the part.
+    seeds: Vec<u64>,
+    seeds_text: &'a str,
+    maps: Vec<Map<'a>>,
+}
+
+impl<'a> Almanac<'a> {
+    fn parse(value: &'a str) -> Result<Self, ParseError> {
+        let blocks = input::blocks(value);
+        let seeds = blocks
+            .first()
+            .and_then(|block| block.first())
+            .ok_or_else(|| ParseError::new(value, "missing seeds"))?;
+        let (seeds, seeds_text) = parse_seeds(seeds)?;
+        Ok(Self {
+            seeds,
+            seeds_text,
+            maps: blocks
+                .iter()
+                .skip(1)
+                .map(Map::try_from)
+                .collect::<Result<Vec<Map>, ParseError>>()?,
+        })
+    }
+    fn process_seeds_to_destination(&self, use_ranged_seeds: &bool) -> Result<Seeds, ParseError> {
+        let seeds = Seeds::new(&self.seeds, self.seeds_text, use_ranged_seeds)?;
+        Ok(self
+            .maps
+            .iter()
+            .fold(seeds, |seeds, map| map.process_seeds(&seeds)))
+    }
+    fn lowest_location(&self, use_ranged_seeds: &bool) -> Result<Answer, ParseError> {
+        Ok(self
+            .process_seeds_to_destination(use_ranged_seeds)?
+            .lowest()
+            .into())
+    }
+}
+
+impl Parsed for Almanac<'_> {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(self.lowest_location(&false)),
+            2 => Some(self.lowest_location(&true)),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Map<'a> {
+    source: &'a str,
+    destination: &'a str,
+    ranges: Vec<Range>,
+}
+
+impl<'a> TryFrom<&Vec<&'a str>> for Map<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &Vec<&'a str>) -> Result<Self, Self::Error> {
+        let header = value[0];
+        let (source, destination) = header
+            .split_once(' ')
+            .and_then(|(source_to_destination, _)| source_to_destination.split_once("-to-"))
+            .ok_or_else(|| ParseError::new(header, "expected `<source>-to-<destination> map:`"))?;
+        let ranges = value
+            .iter()
+            .skip(1)
+            .map(|&r| Range::try_from(r))
+            .collect::<Result<Vec<Range>, ParseError>>()?;
+        Ok(Self {
+            source,
+            destination,
+            ranges,
+        })
+    }
+}
+
+impl Map<'_> {
+    /// Sends each seed through the first range it matches. The parts of a ranged seed outside of
+    /// that range go on to the next ranges, and the seeds matching no range keep their numbers.
+    fn process_seeds(&self, seeds: &Seeds) -> Seeds {
+        trace!(Debug, "process map {}-to-{}", self.source, self.destination);
+        let mut pending_seeds = seeds.0.clone();
+        let mut done_seeds = vec![];
+        for range in &self.ranges {
+            let mut unmatched_seeds = vec![];
+            for seed in pending_seeds {
+                if seed.borrow().match_range(range) {
+                    unmatched_seeds.extend(seed.borrow_mut().split_off(range));
+                    seed.borrow_mut().process_range(range);
+                    done_seeds.push(seed);
+                } else {
+                    unmatched_seeds.push(seed);
+                }
+            }
+            pending_seeds = unmatched_seeds;
+        }
+        done_seeds.extend(pending_seeds);
+        Seeds(done_seeds)
+    }
+}
+
+/// A line of a map, sending `source_start..source_end()` to `destination_start..destination_end()`.
+#[derive(Debug)]
+pub struct Range {
+    pub destination_start: u64,
+    pub source_start: u64,
+    pub len: u64,
+}
+impl Range {
+    pub fn source_end(&self) -> u64 {
+        self.source_start + self.len
+    }
+    pub fn destination_end(&self) -> u64 {
+        self.destination_start + self.len
+    }
+}
+impl Display for Range {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "source_start: {}, source_end : {}, destination_start: {}, destination_end: {}",
+            self.source_start,
+            self.source_end(),
+            self.destination_start,
+            self.destination_end()
+        )
+    }
+}
+impl TryFrom<&str> for Range {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let values = value
+            .split_whitespace()
+            .map(|v| v.parse().map_err(|_| ParseError::new(v, "invalid number")))
+            .collect::<Result<Vec<u64>, ParseError>>()?;
+        match values[..] {
+            [destination_start, source_start, len] => Ok(Self {
+                destination_start,
+                source_start,
+                len,
+            }),
+            _ => Err(ParseError::new(
+                value,
+                "expected `<destination start> <source start> <length>`",
+            )),
+        }
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        5
+    }
+    fn title(&self) -> &'static str {
+        "If You Give A Seed A Fertilizer"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(Almanac::parse(input_content)?))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    Almanac::parse(input_content)?.lowest_location(&false)
+}
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    Almanac::parse(input_content)?.lowest_location(&true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_1_should_report_incomplete_range() {
+        let error = step_1("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").unwrap_err();
+        assert_eq!(error.text, "50 98");
+    }
+
+    #[test]
+    fn step_2_should_keep_seeds_outside_of_a_partially_matching_range() {
+        let input = "seeds: 3 5\n\nseed-to-soil map:\n100 0 5\n";
+        assert_eq!(step_2(input).unwrap(), Answer::from(5u64));
+    }
+
+    #[test]
+    fn step_2_should_find_lowest_location_of_every_seed_of_the_ranges() {
+        let input = include_str!("../../examples/2023/day_5/example_1.txt");
+        let (_, maps) = input.split_once('\n').unwrap();
+        let seeds = (79..79 + 14)
+            .chain(55..55 + 13)
+            .map(|seed: u64| seed.to_string())
+            .collect::<Vec<String>>();
+        let every_seed = format!("seeds: {}\n{maps}", seeds.join(" "));
+        assert_eq!(step_2(input).unwrap(), step_1(&every_seed).unwrap());
+    }
+
+    #[test]
+    fn map_should_send_each_part_of_a_seed_through_its_own_range() {
+        let map = Map::try_from(&vec!["seed-to-soil map:", "100 0 5", "50 5 5"]).unwrap();
+        let seeds = Seeds(vec![Rc::new(RefCell::new(RangedSeed {
+            id: 1,
+            min: 3,
+            max: 8,
+        }))]);
+        let mut processed = map
+            .process_seeds(&seeds)
+            .0
+            .iter()
+            .map(|s| (s.borrow().min(), format!("{:?}", s.borrow())))
+            .collect::<Vec<(u64, String)>>();
+        processed.sort();
+        assert_eq!(
+            processed,
+            [
+                (50, "RangedSeed { id: 1, min: 50, max: 53 }".to_string()),
+                (103, "RangedSeed { id: 1, min: 103, max: 105 }".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_in_range() {
+        let range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 10,
+            source_start: 10,
+            len: 10,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_include_range() {
+        let range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 2,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_start_before_range_and_end_in_range() {
+        let range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 10,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_start_before_range_and_end_after_range() {
+        let range_seed = RangedSeed {id: 1, min: 10, max: 40 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 10,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_start_after_range_and_end_in_range() {
+        let range_seed = RangedSeed {id: 1, min: 20, max: 22 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 10,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_be_processable_when_start_after_range_and_end_after_range() {
+        let range_seed = RangedSeed {id: 1, min: 20, max: 40 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 10,
+        };
+        assert!(range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_seed_should_not_be_processable_when_no_intersections_with_range() {
+        let range_seed = RangedSeed {id: 1, min: 0, max: 5 };
+        let range = Range {
+            destination_start: 15,
+            source_start: 15,
+            len: 10,
+        };
+        assert!(!range_seed.match_range(&range))
+    }
+
+    #[test]
+    fn range_process_should_return_new_range_based_on_parent_range() {
+        let mut range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 20,
+            source_start: 8,
+            len: 13,
+        };
+        range_seed.process_range(&range);
+        assert_eq!(range_seed, RangedSeed {id: 1, min: 22, max: 32 })
+    }
+    #[test]
+    fn range_process_should_return_new_range_based_on_equivalent_range() {
+        let mut range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 20,
+            source_start: 10,
+            len: 10,
+        };
+        range_seed.process_range(&range);
+        assert_eq!(range_seed, RangedSeed {id: 1, min: 20, max: 30 })
+    }
+
+    #[test]
+    fn range_process_should_return_new_range_based_on_lower_start_and_lower_end_range() {
+        let mut range_seed = RangedSeed {id: 1, min: 10, max: 20 };
+        let range = Range {
+            destination_start: 20,
+            source_start: 8,
+            len: 10,
+        };
+        range_seed.process_range(&range);
+        assert_eq!(range_seed, RangedSeed {id: 1, min: 22, max: 30 })
+    }
+}
@@ -0,0 +1,184 @@
+use std::{fmt::Display, iter};
+
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+};
+
+#[derive(Debug, Clone)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+impl Display for Race {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "time: {}, distance: {}", self.time, self.distance)
+    }
+}
+
+/// Joins the digits of the races, failing when the joined numbers don't fit in a `u64`.
+impl<'a> iter::Sum<&'a Race> for Result<Race, ParseError> {
+    fn sum<I: Iterator<Item = &'a Race>>(iter: I) -> Self {
+        let (time, distance) = iter.fold(
+            ("0".to_string(), "0".to_string()),
+            |(time, distance), race| {
+                (
+                    time + &race.time.to_string(),
+                    distance + &race.distance.to_string(),
+                )
+            },
+        );
+        let parse = |digits: String| {
+            digits
+                .parse()
+                .map_err(|_| ParseError::new(&digits, "aggregated number too large"))
+        };
+        Ok(Race {
+            time: parse(time)?,
+            distance: parse(distance)?,
+        })
+    }
+}
+
+impl Race {
+    fn compute_ways_to_win_count(&self) -> u64 {
+        let mut ways_to_win = 0;
+        for i in 1..self.time.saturating_sub(1) {
+            let distance = Self::get_distance_by_time(&i, &self.time);
+            if distance > self.distance {
+                ways_to_win += 1;
+            }
+        }
+        ways_to_win
+    }
+
+    fn get_distance_by_time(hold_time: &u64, max_time: &u64) -> u64 {
+        hold_time * (max_time - hold_time)
+    }
+}
+#[derive(Debug)]
+struct LeaderBoard(Vec<Race>);
+
+impl TryFrom<&str> for LeaderBoard {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut lines = input::items(value, '\n');
+        let (Some(times), Some(distances)) = (lines.next(), lines.next()) else {
+            return Err(ParseError::new(value, "expected a time line and a distance line"));
+        };
+        let (times, distances) = (get_line_values(times)?, get_line_values(distances)?);
+        if times.len() != distances.len() {
+            return Err(ParseError::new(
+                value,
+                "times and distances counts are different",
+            ));
+        }
+        Ok(Self(
+            times
+                .iter()
+                .zip(distances.iter())
+                .map(|(&time, &distance)| Race { time, distance })
+                .collect(),
+        ))
+    }
+}
+
+impl LeaderBoard {
+    fn get_total_ways_to_win(&self) -> u64 {
+        self.0
+            .iter()
+            .map(|r| r.compute_ways_to_win_count())
+            .product()
+    }
+    fn get_total_ways_to_win_for_aggregated_races(&self) -> Result<u64, ParseError> {
+        let race = self.0.iter().sum::<Result<Race, ParseError>>()?;
+        Ok(race.compute_ways_to_win_count())
+    }
+}
+
+impl Parsed for LeaderBoard {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        match part {
+            1 => Some(Ok(self.get_total_ways_to_win().into())),
+            2 => Some(
+                self.get_total_ways_to_win_for_aggregated_races()
+                    .map(Answer::from),
+            ),
+            _ => None,
+        }
+    }
+}
+
+fn get_line_values(line: &str) -> Result<Vec<u64>, ParseError> {
+    let (_, values) = line
+        .split_once(':')
+        .ok_or_else(|| ParseError::new(line, "expected `<name>: <value>...`"))?;
+    values
+        .split_whitespace()
+        .map(|v| {
+            v.parse::<u64>()
+                .map_err(|_| ParseError::new(v, "invalid number"))
+        })
+        .collect()
+}
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        6
+    }
+    fn title(&self) -> &'static str {
+        "Wait For It"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(LeaderBoard::try_from(input_content)?))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    let leaderboard = LeaderBoard::try_from(input_content)?;
+    Ok(leaderboard.get_total_ways_to_win().into())
+}
+
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    let leaderboard = LeaderBoard::try_from(input_content)?;
+    Ok(leaderboard
+        .get_total_ways_to_win_for_aggregated_races()?
+        .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_1_should_report_missing_distances() {
+        let error = step_1("Time:      7  15   30").unwrap_err();
+        assert_eq!(error.reason, "expected a time line and a distance line");
+    }
+
+    #[test]
+    fn races_without_time_should_have_no_way_to_win() {
+        assert_eq!(
+            step_1("Time: 0 1\nDistance: 0 0").unwrap(),
+            Answer::from(0u64)
+        );
+        assert_eq!(step_2("Time: 0\nDistance: 0").unwrap(), Answer::from(0u64));
+    }
+
+    #[test]
+    fn step_2_should_report_too_large_aggregated_race() {
+        let error = step_2("Time: 7 99999999999 99999999999\nDistance: 9 40 200").unwrap_err();
+        assert_eq!(error.reason, "aggregated number too large");
+        assert_eq!(error.text, "079999999999999999999999");
+    }
+}
@@ -0,0 +1,320 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    answer::Answer,
+    input,
+    parse_error::ParseError,
+    solution::{Params, Parsed, Solution},
+};
+
+#[derive(Debug)]
+enum Direction {
+    L,
+    R,
+}
+
+impl Direction {
+    fn choose_node<'a>(&self, options: &'a NodeOptions<'a>) -> &'a Node<'a> {
+        match *self {
+            Direction::L => &options.0,
+            Direction::R => &options.1,
+        }
+    }
+}
+#[derive(Hash, PartialEq, Eq, Debug)]
+struct Node<'a>(&'a str);
+impl<'a> From<&'a str> for Node<'a> {
+    fn from(value: &'a str) -> Self {
+        Node(value.trim())
+    }
+}
+
+impl Node<'_> {
+    fn start() -> Self {
+        Node("AAA")
+    }
+    fn end_node() -> Self {
+        Node("ZZZ")
+    }
+    fn is_end(&self) -> bool {
+        self == &Node::end_node()
+    }
+    fn is_ghost_start(&self) -> bool {
+        self.0.ends_with('A')
+    }
+    fn is_ghost_end(&self) -> bool {
+        self.0.ends_with('Z')
+    }
+}
+
+#[derive(Debug)]
+struct NodeOptions<'a>(Node<'a>, Node<'a>);
+
+impl<'a> TryFrom<&'a str> for NodeOptions<'a> {
+    type Error = ParseError;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let (n1, n2) = value
+            .strip_prefix('(')
+            .and_then(|v| v.strip_suffix(')'))
+            .and_then(|v| v.split_once(','))
+            .ok_or_else(|| ParseError::new(value, "expected `(<left>, <right>)`"))?;
+        Ok(Self(Node::from(n1), Node::from(n2)))
+    }
+}
+
+impl TryFrom<&str> for Direction {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "L" => Ok(Self::L),
+            "R" => Ok(Self::R),
+            _ => Err(ParseError::new(value, "unknown direction")),
+        }
+    }
+}
+
+type NodesMap<'a> = HashMap<Node<'a>, NodeOptions<'a>>;
+
+fn parse(input_content: &str) -> Result<(Vec<Direction>, NodesMap<'_>), ParseError> {
+    let blocks = input::blocks(input_content);
+    let directions_line = blocks
+        .first()
+        .and_then(|block| block.first())
+        .ok_or_else(|| ParseError::new(input_content, "missing directions"))?;
+    let directions = directions_line
+        .char_indices()
+        .map(|(i, c)| Direction::try_from(&directions_line[i..i + c.len_utf8()]))
+        .collect::<Result<Vec<Direction>, ParseError>>()?;
+    let nodes_map = blocks
+        .iter()
+        .flatten()
+        .skip(1)
+        .map(|n: &&str| {
+            let (target_node, node_options) = n
+                .split_once('=')
+                .ok_or_else(|| ParseError::new(n, "expected `<node> = (<left>, <right>)`"))?;
+            Ok((
+                Node::from(target_node),
+                NodeOptions::try_from(node_options)?,
+            ))
+        })
+        .collect::<Result<NodesMap, ParseError>>()?;
+    for NodeOptions(left, right) in nodes_map.values() {
+        for node in [left, right] {
+            if !nodes_map.contains_key(node) {
+                return Err(ParseError::new(node.0, "unknown node"));
+            }
+        }
+    }
+    Ok((directions, nodes_map))
+}
+
+/// The number of steps from `start` to the first node matching `is_end`, `None` when the walk
+/// comes back to a node at the same direction without having met one.
+fn count_steps<'a>(
+    directions: &[Direction],
+    nodes_map: &'a NodesMap<'a>,
+    start: &'a Node<'a>,
+    is_end: impl Fn(&Node<'a>) -> bool,
+) -> Option<u64> {
+    let mut visited = HashSet::new();
+    let mut node = start;
+    let mut step = 0;
+    while !is_end(node) {
+        let direction_idx = step % directions.len();
+        if !visited.insert((node, direction_idx)) {
+            return None;
+        }
+        node = directions[direction_idx].choose_node(&nodes_map[node]);
+        step += 1;
+    }
+    Some(step as u64)
+}
+
+/// Whether the ghost leaving `start` is on an end node exactly every `steps` steps. The walk
+/// stops once it comes back to a node at the same direction, the ghost repeating the same loop
+/// from there on.
+fn ends_every<'a>(
+    directions: &[Direction],
+    nodes_map: &'a NodesMap<'a>,
+    start: &'a Node<'a>,
+    steps: u64,
+) -> bool {
+    let mut visited = HashMap::new();
+    let mut node = start;
+    let mut step = 0;
+    loop {
+        let direction_idx = step as usize % directions.len();
+        if let Some(loop_start) = visited.insert((node, direction_idx), step) {
+            return (step - loop_start) % steps == 0;
+        }
+        if node.is_ghost_end() != (step > 0 && step % steps == 0) {
+            return false;
+        }
+        node = directions[direction_idx].choose_node(&nodes_map[node]);
+        step += 1;
+    }
+}
+
+/// The number of steps between two end nodes of each ghost, checking every ghost does loop on
+/// its end nodes: nothing in the puzzle says so, but its inputs are made that way.
+fn ghost_cycles(directions: &[Direction], nodes_map: &NodesMap) -> Result<Vec<u64>, ParseError> {
+    nodes_map
+        .keys()
+        .filter(|n| n.is_ghost_start())
+        .map(|start| {
+            let steps = count_steps(directions, nodes_map, start, Node::is_ghost_end)
+                .ok_or_else(|| ParseError::new(start.0, "ghost never reaching an end node"))?;
+            if !ends_every(directions, nodes_map, start, steps) {
+                return Err(ParseError::new(
+                    start.0,
+                    "ghost not reaching an end node at regular intervals",
+                ));
+            }
+            Ok(steps)
+        })
+        .collect()
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn day(&self) -> u8 {
+        8
+    }
+    fn title(&self) -> &'static str {
+        "Haunted Wasteland"
+    }
+    fn parse<'a>(&self, input_content: &'a str) -> Result<Box<dyn Parsed + 'a>, ParseError> {
+        Ok(Box::new(parse(input_content)?))
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+    fn part_two(&self, input_content: &str) -> Option<Result<Answer, ParseError>> {
+        Some(step_2(input_content))
+    }
+}
+
+fn walk_to_end(directions: &[Direction], nodes_map: &NodesMap) -> Result<Answer, ParseError> {
+    let (start, end) = (Node::start(), Node::end_node());
+    if !nodes_map.contains_key(&start) {
+        return Err(ParseError::new(start.0, "missing start node"));
+    }
+    if !nodes_map.contains_key(&end) {
+        return Err(ParseError::new(end.0, "missing end node"));
+    }
+    count_steps(directions, nodes_map, &start, Node::is_end)
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::new(end.0, "end node out of reach of the start node"))
+}
+
+/// Every ghost being on an end node at regular intervals, all of them meet at the least common
+/// multiple of these intervals.
+fn meet_ghosts(directions: &[Direction], nodes_map: &NodesMap) -> Result<Answer, ParseError> {
+    Ok(ghost_cycles(directions, nodes_map)?
+        .into_iter()
+        .fold(1, |lcm, steps| lcm / gcd(lcm, steps) * steps)
+        .into())
+}
+
+impl Parsed for (Vec<Direction>, NodesMap<'_>) {
+    fn solve(&self, part: u8, _params: &Params) -> Option<Result<Answer, ParseError>> {
+        let (directions, nodes_map) = self;
+        match part {
+            1 => Some(walk_to_end(directions, nodes_map)),
+            2 => Some(meet_ghosts(directions, nodes_map)),
+            _ => None,
+        }
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    let (directions, nodes_map) = parse(input_content)?;
+    walk_to_end(&directions, &nodes_map)
+}
+
+pub fn step_2(input_content: &str) -> Result<Answer, ParseError> {
+    let (directions, nodes_map) = parse(input_content)?;
+    meet_ghosts(&directions, &nodes_map)
+}
+
+/// Walks all the ghosts step by step until they are on end nodes together, giving the answer of
+/// [`step_2`] far too slowly for the puzzle input: kept to check and benchmark the shortcut.
+pub fn step(input_content: &str) -> Result<Answer, ParseError> {
+    let (directions, nodes_map) = parse(input_content)?;
+    ghost_cycles(&directions, &nodes_map)?;
+    let mut nodes = nodes_map
+        .keys()
+        .filter(|n| n.is_ghost_start())
+        .collect::<Vec<&Node<'_>>>();
+    let mut step = 0;
+    while !nodes.iter().all(|n| n.is_ghost_end()) {
+        let direction = &directions[step % directions.len()];
+        for node in nodes.iter_mut() {
+            *node = direction.choose_node(&nodes_map[*node]);
+        }
+        step += 1;
+    }
+    Ok((step as u64).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_1_should_report_unknown_node() {
+        let input = "L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        let error = step_1(input).unwrap_err();
+        assert_eq!(error.text, "BBB");
+    }
+
+    #[test]
+    fn step_1_should_report_missing_or_unreachable_end_node() {
+        let error = step_1("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)").unwrap_err();
+        assert_eq!(error.reason, "missing end node");
+        let input = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, AAA)";
+        let error = step_1(input).unwrap_err();
+        assert_eq!(error.reason, "end node out of reach of the start node");
+    }
+
+    #[test]
+    fn step_1_should_only_walk_from_aaa_to_zzz() {
+        let input = "L\n\nAAA = (ZZZ, ZZZ)\nBBA = (BBA, BBA)\nZZZ = (ZZZ, ZZZ)";
+        assert_eq!(step_1(input).unwrap(), Answer::from(1u64));
+    }
+
+    #[test]
+    fn step_2_should_meet_at_least_common_multiple_of_ghost_cycles() {
+        let input = "L\n\n\
+            XXA = (XXB, XXB)\nXXB = (XXC, XXC)\nXXC = (XXD, XXD)\nXXD = (XXZ, XXZ)\n\
+            XXZ = (XXB, XXB)\n\
+            YYA = (YYB, YYB)\nYYB = (YYC, YYC)\nYYC = (YYD, YYD)\nYYD = (YYE, YYE)\n\
+            YYE = (YYF, YYF)\nYYF = (YYZ, YYZ)\nYYZ = (YYB, YYB)";
+        assert_eq!(step_2(input).unwrap(), Answer::from(12u64));
+        assert_eq!(step(input).unwrap(), Answer::from(12u64));
+    }
+
+    #[test]
+    fn step_2_should_report_ghost_not_looping_on_its_end_node() {
+        let input = "L\n\nAAA = (BBZ, BBZ)\nBBZ = (CCC, CCC)\nCCC = (CCC, CCC)";
+        let error = step_2(input).unwrap_err();
+        assert_eq!(error.text, "AAA");
+        assert_eq!(
+            error.reason,
+            "ghost not reaching an end node at regular intervals"
+        );
+    }
+}
@@ -0,0 +1,33 @@
+use crate::{answer::Answer, parse_error::ParseError, solution::Solution};
+
+pub struct Challenge;
+
+impl Solution for Challenge {
+    fn year(&self) -> u16 {
+        {{year}}
+    }
+    fn day(&self) -> u8 {
+        {{day}}
+    }
+    fn title(&self) -> &'static str {
+        "Day {{day}}"
+    }
+    fn part_one(&self, input_content: &str) -> Result<Answer, ParseError> {
+        step_1(input_content)
+    }
+}
+
+pub fn step_1(input_content: &str) -> Result<Answer, ParseError> {
+    let res = input_content.lines().count() as u64;
+    Ok(res.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn step_1_should_count_lines() {
+        assert_eq!(step_1("a\nb").unwrap(), Answer::from(2u64));
+    }
+}
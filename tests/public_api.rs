@@ -0,0 +1,26 @@
+use advent_of_code_2023::{
+    matrix::MatrixWrapper,
+    point::Point,
+    registry,
+    year_2023::{challenge_15::Step, challenge_5::Range},
+    Answer,
+};
+
+#[test]
+fn registered_solutions_should_be_usable_from_another_crate() {
+    let solution = registry::find(2023, 6).unwrap();
+    let answer = solution.part(1, "Time: 7 15 30\nDistance: 9 40 200");
+    assert_eq!(answer.unwrap().unwrap(), Answer::Unsigned(288));
+}
+
+#[test]
+fn shared_utilities_should_be_usable_from_another_crate() {
+    assert_eq!(Step("HASH").hash(), 52);
+    let range = Range::try_from("50 98 2").unwrap();
+    assert_eq!((range.source_end(), range.destination_end()), (100, 52));
+    let matrix = ["ab", "cd"]
+        .iter()
+        .map(|l| l.chars().collect())
+        .collect::<MatrixWrapper<char>>();
+    assert_eq!(matrix.at(Point::new(1, 0)), Some(&'c'));
+}